@@ -0,0 +1,178 @@
+use color_eyre::eyre::{bail, eyre, Result, WrapErr};
+
+use advent2021::days::Day;
+use advent2021::input::Source;
+use advent2021::solution::Settings;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+/// Something we time: parsing a day's input, or solving one of its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "{}", part),
+        }
+    }
+}
+
+impl std::str::FromStr for Step {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Step> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            _ => Ok(Step::Part(
+                s.parse().wrap_err_with(|| format!("bad step {:?}", s))?,
+            )),
+        }
+    }
+}
+
+/// Timings by day, step and setup. Timings on different inputs or with different options say
+/// nothing about each other, so they're kept apart.
+pub type Timings = HashMap<(u8, Step, String), Duration>;
+
+/// Describes what a day was timed on, like `default` (its usual input file), `example days=18`
+/// or `input/big.txt`.
+pub fn setup(source: &Source, settings: &Settings) -> String {
+    let mut setup = source.to_string();
+    for (name, value) in settings {
+        setup += &format!(" {}={}", name, value);
+    }
+    setup
+}
+
+fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort_unstable();
+    times[times.len() / 2]
+}
+
+/// Runs a day `runs` times, returning the median time of parsing and of each part.
+pub fn measure(
+    day: &Day,
+    input: &str,
+    parts: &[u8],
+    settings: &Settings,
+    runs: usize,
+) -> Result<Vec<(Step, Duration)>> {
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut part_times = vec![Vec::with_capacity(runs); parts.len()];
+    for _ in 0..runs {
+        let run = day.run(input, parts, settings)?;
+        parse_times.push(run.parse_time);
+        for (times, part) in part_times.iter_mut().zip(run.parts) {
+            part.answer
+                .wrap_err_with(|| format!("day {} part {}", day.number, part.part))?;
+            times.push(part.time);
+        }
+    }
+
+    let mut medians = vec![(Step::Parse, median(parse_times))];
+    for (&part, times) in parts.iter().zip(part_times) {
+        medians.push((Step::Part(part), median(times)));
+    }
+    Ok(medians)
+}
+
+/// Reads a baseline written by [`save`]: one `<day> <step> <nanoseconds> <setup>` line per
+/// timing.
+pub fn load(path: &Path) -> Result<Timings> {
+    let contents = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("reading baseline {}", path.display()))?;
+
+    let mut timings = HashMap::new();
+    for (number, line) in contents.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let bad_line = || {
+            eyre!(
+                "{}:{}: bad baseline line {:?}",
+                path.display(),
+                number + 1,
+                line
+            )
+        };
+        // The setup can have spaces in it, so it's everything after the third field.
+        let fields: Vec<_> = line.splitn(4, ' ').collect();
+        if fields.len() == 3 {
+            bail!(
+                "{}:{}: timing {:?} doesn't say what input and settings it was for; save the \
+                 baseline again",
+                path.display(),
+                number + 1,
+                line
+            );
+        }
+        if fields.len() != 4 || fields[3].is_empty() {
+            return Err(bad_line());
+        }
+        let day = fields[0].parse().map_err(|_| bad_line())?;
+        let step = fields[1].parse().map_err(|_| bad_line())?;
+        let nanos = fields[2].parse().map_err(|_| bad_line())?;
+        let setup = fields[3].to_owned();
+        timings.insert((day, step, setup), Duration::from_nanos(nanos));
+    }
+    Ok(timings)
+}
+
+/// Writes `timings` to `path`, keeping any timings already there for days, steps and setups we
+/// didn't measure this time.
+pub fn save(path: &Path, timings: &Timings) -> Result<()> {
+    let mut merged = if path.exists() {
+        load(path)?
+    } else {
+        HashMap::new()
+    };
+    merged.extend(timings.iter().map(|(key, &time)| (key.clone(), time)));
+
+    let mut keys: Vec<_> = merged.keys().collect();
+    keys.sort_unstable();
+
+    let mut contents = String::from("# day step nanoseconds setup\n");
+    for key in keys {
+        let (day, step, setup) = key;
+        contents += &format!("{} {} {} {}\n", day, step, merged[key].as_nanos(), setup);
+    }
+    std::fs::write(path, contents).wrap_err_with(|| format!("writing baseline {}", path.display()))
+}
+
+/// How a timing compares to its baseline, as a note for the table.
+pub struct Comparison {
+    pub note: String,
+    pub regressed: bool,
+}
+
+pub fn compare(time: Duration, baseline: Option<Duration>, threshold: f64) -> Comparison {
+    let baseline = match baseline {
+        Some(baseline) if !baseline.is_zero() => baseline,
+        _ => {
+            return Comparison {
+                note: "no baseline".to_owned(),
+                regressed: false,
+            }
+        }
+    };
+
+    let change = (time.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+    let regressed = change > threshold;
+    Comparison {
+        note: format!(
+            "was {:.1?}, {:+.1}%{}",
+            baseline,
+            change,
+            if regressed { "  REGRESSION" } else { "" }
+        ),
+        regressed,
+    }
+}
@@ -0,0 +1,446 @@
+use color_eyre::eyre::{bail, eyre, Result, WrapErr};
+
+use advent2021::days;
+use advent2021::generate;
+use advent2021::input::Source;
+use advent2021::solution::Run;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+mod bench;
+
+const USAGE: &str =
+    "usage: advent run (--all | --day N[-M]...) [--part 1|2] [--example | --input PATH]
+                  [--set NAME=VALUE...]
+       advent bench (--all | --day N[-M]...) [--part 1|2] [--example | --input PATH]
+                    [--set NAME=VALUE...]
+                    [--runs N] [--save FILE] [--baseline FILE] [--threshold PERCENT]
+       advent generate --day N [--size N] [--seed N] [--output PATH]
+
+    --all               run every day
+    --day N[-M]         run day N, or days N through M; may be repeated
+    --part P            only run part P
+    --example           use the example from the puzzle text
+    --input PATH        read input from PATH instead of input/<day>.txt (`-` for stdin)
+    --set NAME=VALUE    change one of the days' options, like `--set days=100` for day 6; every
+                        selected day has to have the option; may be repeated
+
+  run only:
+    --format FORMAT     print a table (`text`, the default) or one JSON object per part (`json`)
+
+  bench only:
+    --runs N            time each day N times and report the median (default 5)
+    --save FILE         write the timings to FILE, to compare later runs against
+    --baseline FILE     compare the timings against ones saved in FILE
+    --threshold PERCENT how much slower than the baseline counts as a regression (default 10)
+
+  generate only:
+    --size N            how much input to make; what it counts depends on the day
+    --seed N            make a different input for each seed, the same one every time (default 0)
+    --output PATH       write the input to PATH instead of stdout";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run,
+    Bench,
+    Generate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug)]
+struct Options {
+    command: Command,
+    format: Format,
+    days: Vec<u8>,
+    parts: Vec<u8>,
+    source: Source,
+    settings: Vec<(String, String)>,
+    runs: usize,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+    size: Option<usize>,
+    seed: u64,
+    output: Option<PathBuf>,
+}
+
+fn parse_day(s: &str) -> Result<u8> {
+    let day = s.parse().wrap_err_with(|| format!("bad day {:?}", s))?;
+    if !(1..=25).contains(&day) {
+        bail!("there is no day {}", day);
+    }
+    Ok(day)
+}
+
+fn parse_days(spec: &str) -> Result<Vec<u8>> {
+    let (first, last) = match spec.split_once('-') {
+        Some((first, last)) => (parse_day(first)?, parse_day(last)?),
+        None => (parse_day(spec)?, parse_day(spec)?),
+    };
+    if first > last {
+        bail!("bad range of days {:?}", spec);
+    }
+    Ok((first..=last).collect())
+}
+
+fn parse_part(s: &str) -> Result<u8> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => bail!("bad part {:?}, expected 1 or 2", s),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        Some("generate") => Command::Generate,
+        Some(command) => bail!("unknown command {:?}\n\n{}", command, USAGE),
+        None => bail!("{}", USAGE),
+    };
+
+    let mut options = Options {
+        command,
+        format: Format::Text,
+        days: Vec::new(),
+        parts: vec![1, 2],
+        source: Source::Default,
+        settings: Vec::new(),
+        runs: 5,
+        save: None,
+        baseline: None,
+        threshold: 10.0,
+        size: None,
+        seed: 0,
+        output: None,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| eyre!("{} needs a value", arg));
+        let bench_only = matches!(
+            arg.as_str(),
+            "--runs" | "--save" | "--baseline" | "--threshold"
+        );
+        if bench_only && options.command != Command::Bench {
+            bail!("{} only applies to `advent bench`", arg);
+        }
+        if arg == "--format" && options.command != Command::Run {
+            bail!("{} only applies to `advent run`", arg);
+        }
+        let generate_only = matches!(arg.as_str(), "--size" | "--seed" | "--output");
+        if generate_only && options.command != Command::Generate {
+            bail!("{} only applies to `advent generate`", arg);
+        }
+        let solving_only = matches!(
+            arg.as_str(),
+            "--all" | "--part" | "--example" | "--input" | "--set"
+        );
+        if solving_only && options.command == Command::Generate {
+            bail!("{} doesn't apply to `advent generate`", arg);
+        }
+        match arg.as_str() {
+            "--all" => options.days.extend(1..=25),
+            "--day" => options.days.extend(parse_days(&value()?)?),
+            "--part" => options.parts = vec![parse_part(&value()?)?],
+            "--example" => options.source = Source::Example,
+            "--input" => {
+                options.source = match value()?.as_str() {
+                    "-" => Source::Stdin,
+                    path => Source::File(path.into()),
+                }
+            }
+            "--set" => {
+                let setting = value()?;
+                let (name, value) = setting
+                    .split_once('=')
+                    .ok_or_else(|| eyre!("bad setting {:?}, expected NAME=VALUE", setting))?;
+                options.settings.push((name.to_owned(), value.to_owned()));
+            }
+            "--runs" => {
+                let runs = value()?;
+                options.runs = runs
+                    .parse()
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or_else(|| eyre!("bad number of runs {:?}", runs))?;
+            }
+            "--format" => {
+                options.format = match value()?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    format => bail!("bad format {:?}, expected text or json", format),
+                }
+            }
+            "--save" => options.save = Some(value()?.into()),
+            "--baseline" => options.baseline = Some(value()?.into()),
+            "--threshold" => {
+                let threshold = value()?;
+                options.threshold = threshold
+                    .parse()
+                    .wrap_err_with(|| format!("bad threshold {:?}", threshold))?;
+            }
+            "--size" => {
+                let size = value()?;
+                options.size = Some(
+                    size.parse()
+                        .wrap_err_with(|| format!("bad size {:?}", size))?,
+                );
+            }
+            "--seed" => {
+                let seed = value()?;
+                options.seed = seed
+                    .parse()
+                    .wrap_err_with(|| format!("bad seed {:?}", seed))?;
+            }
+            "--output" => options.output = Some(value()?.into()),
+            _ => bail!("unexpected argument {:?}\n\n{}", arg, USAGE),
+        }
+    }
+
+    options.days.sort_unstable();
+    options.days.dedup();
+    if options.days.is_empty() {
+        bail!("no days selected\n\n{}", USAGE);
+    }
+    if matches!(options.source, Source::File(_) | Source::Stdin) && options.days.len() > 1 {
+        bail!("--input can only be used when running a single day");
+    }
+    if options.command == Command::Generate && options.days.len() > 1 {
+        bail!("can only generate one day's input at a time");
+    }
+
+    Ok(options)
+}
+
+fn print_row(day: u8, part: &str, time: &str, answer: &str) {
+    // Multi-line answers (like day 13's folded paper) continue under the answer column.
+    let mut lines = answer.lines();
+    println!(
+        "{:>3}  {:>4}  {:>10}  {}",
+        day,
+        part,
+        time,
+        lines.next().unwrap_or("")
+    );
+    for line in lines {
+        println!("{:23}{}", "", line);
+    }
+}
+
+/// Solves each requested day and part, returning how many failed.
+fn run(options: &Options) -> usize {
+    if options.format == Format::Text {
+        println!("{:>3}  {:>4}  {:>10}  answer", "day", "part", "time");
+    }
+    let mut failures = 0;
+    for &number in &options.days {
+        let day = &days::ALL[usize::from(number) - 1];
+
+        let run = options
+            .source
+            .read(number, day.example)
+            .and_then(|input| day.run(&input, &options.parts, &options.settings));
+        failures += match &run {
+            Ok(run) => run.parts.iter().filter(|part| part.answer.is_err()).count(),
+            Err(_) => 1,
+        };
+
+        match options.format {
+            Format::Text => print_text(number, &run),
+            Format::Json => print_json(number, &options.source, &options.parts, &run),
+        }
+    }
+
+    failures
+}
+
+fn print_text(day: u8, run: &Result<Run>) {
+    let run = match run {
+        Ok(run) => run,
+        Err(e) => return print_row(day, "-", "-", &format!("error: {:#}", e)),
+    };
+
+    print_row(day, "-", &format!("{:.1?}", run.parse_time), "(parse)");
+    for part in &run.parts {
+        let time = format!("{:.1?}", part.time);
+        match &part.answer {
+            Ok(answer) => print_row(day, &part.part.to_string(), &time, answer),
+            Err(e) => print_row(
+                day,
+                &part.part.to_string(),
+                &time,
+                &format!("error: {:#}", e),
+            ),
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Prints one JSON object per line for each requested part. If the day couldn't be read or
+/// parsed, every part gets that error.
+fn print_json(day: u8, source: &Source, parts: &[u8], run: &Result<Run>) {
+    let source = json_string(&source.to_string());
+    let null = || "null".to_owned();
+    let print = |part: u8, answer: String, error: String, parse_ns: String, time_ns: String| {
+        println!(
+            "{{\"day\":{},\"part\":{},\"source\":{},\"answer\":{},\"error\":{},\"parse_ns\":{},\"time_ns\":{}}}",
+            day, part, source, answer, error, parse_ns, time_ns
+        )
+    };
+
+    match run {
+        Ok(run) => {
+            for part in &run.parts {
+                let (answer, error) = match &part.answer {
+                    Ok(answer) => (json_string(answer), null()),
+                    Err(e) => (null(), json_string(&format!("{:#}", e))),
+                };
+                print(
+                    part.part,
+                    answer,
+                    error,
+                    run.parse_time.as_nanos().to_string(),
+                    part.time.as_nanos().to_string(),
+                );
+            }
+        }
+        Err(e) => {
+            for &part in parts {
+                print(
+                    part,
+                    null(),
+                    json_string(&format!("{:#}", e)),
+                    null(),
+                    null(),
+                );
+            }
+        }
+    }
+}
+
+/// Times each requested day and part, comparing against a baseline if we have one. Returns how
+/// many days failed or regressed.
+fn bench(options: &Options) -> Result<usize> {
+    let baseline = match &options.baseline {
+        Some(path) => bench::load(path)?,
+        None => HashMap::new(),
+    };
+
+    println!(
+        "{:>3}  {:>4}  {:>10}  compared to baseline",
+        "day", "part", "median"
+    );
+    let mut failures = 0;
+    let mut timings = HashMap::new();
+    let setup = bench::setup(&options.source, &options.settings);
+    for &number in &options.days {
+        let day = &days::ALL[usize::from(number) - 1];
+
+        let measured = options.source.read(number, day.example).and_then(|input| {
+            bench::measure(day, &input, &options.parts, &options.settings, options.runs)
+        });
+        let measured = match measured {
+            Ok(measured) => measured,
+            Err(e) => {
+                print_row(number, "-", "-", &format!("error: {:#}", e));
+                failures += 1;
+                continue;
+            }
+        };
+
+        for (step, time) in measured {
+            let key = (number, step, setup.clone());
+            let comparison = bench::compare(time, baseline.get(&key).copied(), options.threshold);
+            if comparison.regressed {
+                failures += 1;
+            }
+            let part = match step {
+                bench::Step::Parse => "-".to_owned(),
+                bench::Step::Part(part) => part.to_string(),
+            };
+            print_row(number, &part, &format!("{:.1?}", time), &comparison.note);
+            timings.insert(key, time);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        bench::save(path, &timings)?;
+    }
+    Ok(failures)
+}
+
+/// Writes a random input for the requested day.
+fn generate(options: &Options) -> Result<()> {
+    let number = options.days[0];
+    let generator = &generate::ALL[usize::from(number) - 1];
+    let size = match (generator.size, options.size) {
+        (Some(size), requested) => {
+            let requested = requested.unwrap_or(size.default).max(size.min);
+            eprintln!(
+                "day {}: {} {}, seed {}",
+                number, requested, size.counts, options.seed
+            );
+            requested
+        }
+        (None, Some(_)) => bail!("day {}'s input doesn't come in sizes", number),
+        (None, None) => {
+            eprintln!("day {}: seed {}", number, options.seed);
+            0
+        }
+    };
+
+    let input = generator.generate(size, options.seed) + "\n";
+    match &options.output {
+        Some(path) => {
+            std::fs::write(path, input).wrap_err_with(|| format!("writing {}", path.display()))
+        }
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let options = parse_args(std::env::args().skip(1))?;
+
+    match options.command {
+        Command::Run => match run(&options) {
+            0 => Ok(()),
+            failures => bail!("{} of the requested days or parts failed", failures),
+        },
+        Command::Bench => match bench(&options)? {
+            0 => Ok(()),
+            failures => bail!(
+                "{} of the requested days or parts failed or regressed",
+                failures
+            ),
+        },
+        Command::Generate => generate(&options),
+    }
+}
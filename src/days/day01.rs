@@ -0,0 +1,44 @@
+use color_eyre::eyre::Result;
+
+use crate::parse::Text;
+use crate::Solution;
+
+fn increases(readings: &[u32]) -> usize {
+    readings.windows(2).filter(|pair| pair[1] > pair[0]).count()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<u32>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<u32>> {
+        Ok(Text::new(Self::DAY, input).records(input)?)
+    }
+
+    fn part_one(readings: &Vec<u32>, _: &()) -> Result<usize> {
+        Ok(increases(readings))
+    }
+
+    fn part_two(readings: &Vec<u32>, _: &()) -> Result<usize> {
+        let windows: Vec<u32> = readings.windows(3).map(|m| m.iter().sum()).collect();
+        Ok(increases(&windows))
+    }
+}
+
+pub const EXAMPLE: &str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
@@ -0,0 +1,74 @@
+use color_eyre::eyre::Result;
+use reformation::Reformation;
+
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Reformation)]
+pub enum Command {
+    #[reformation("forward {}")]
+    Forward(u32),
+    #[reformation("down {}")]
+    Down(u32),
+    #[reformation("up {}")]
+    Up(u32),
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<Command>;
+    type Options = ();
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<Command>> {
+        Ok(Text::new(Self::DAY, input).records(input)?)
+    }
+
+    fn part_one(commands: &Vec<Command>, _: &()) -> Result<u32> {
+        let mut horizontal = 0;
+        let mut depth = 0;
+
+        for command in commands {
+            use Command::*;
+            match command {
+                Forward(amount) => horizontal += amount,
+                Down(amount) => depth += amount,
+                Up(amount) => depth -= amount,
+            }
+        }
+
+        Ok(horizontal * depth)
+    }
+
+    fn part_two(commands: &Vec<Command>, _: &()) -> Result<u32> {
+        let mut horizontal = 0;
+        let mut depth = 0;
+        let mut aim = 0;
+
+        for command in commands {
+            use Command::*;
+            match command {
+                Forward(amount) => {
+                    horizontal += amount;
+                    depth += amount * aim;
+                }
+                Down(amount) => aim += amount,
+                Up(amount) => aim -= amount,
+            }
+        }
+
+        Ok(horizontal * depth)
+    }
+}
+
+pub const EXAMPLE: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
@@ -0,0 +1,138 @@
+use color_eyre::eyre::{bail, eyre, Result};
+
+use crate::parse::Text;
+use crate::Solution;
+
+fn filter_bits(mut candidates: Vec<&[u8]>, ones_more_freq: u8) -> Result<&[u8]> {
+    let zeros_more_freq = if ones_more_freq == b'1' { b'0' } else { b'1' };
+    let digits = candidates.first().map_or(0, |s| s.len());
+
+    for digit in 0..digits {
+        if candidates.len() <= 1 {
+            break;
+        }
+        let ones: usize = candidates
+            .iter()
+            .map(|s| if s[digit] == b'1' { 1 } else { 0 })
+            .sum();
+        let zeros = candidates.len() - ones;
+        if ones == 0 || zeros == 0 {
+            // Every candidate agrees, so there's nothing to filter on.
+            continue;
+        }
+        let selected = match ones >= zeros {
+            true => ones_more_freq,
+            false => zeros_more_freq,
+        };
+        candidates.retain(|s| s[digit] == selected);
+    }
+
+    match candidates[..] {
+        [rating] => Ok(rating),
+        [] => bail!("no numbers to find a rating in"),
+        _ => bail!(
+            "{} numbers are the same, so no one rating",
+            candidates.len()
+        ),
+    }
+}
+
+/// Reads a rate's binary digits.
+fn rate(digits: &[u8]) -> Result<u64> {
+    let digits = std::str::from_utf8(digits)?;
+    u64::from_str_radix(digits, 2)
+        .map_err(|_| eyre!("{} digits are too many for a rate", digits.len()))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<Vec<u8>>;
+    type Options = ();
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u8>>> {
+        let text = Text::new(Self::DAY, input);
+        let width = input.split("\n").next().map_or(0, str::len);
+        if width == 0 {
+            return Err(text.error(input, "expected binary numbers").into());
+        }
+        input
+            .split("\n")
+            .map(|line| {
+                if let Some(i) = line.find(|c| c != '0' && c != '1') {
+                    return Err(text.error(&line[i..], "expected a binary digit").into());
+                }
+                if line.len() != width {
+                    return Err(text
+                        .error(
+                            line,
+                            format!("expected {} digits like the first line", width),
+                        )
+                        .into());
+                }
+                Ok(line.as_bytes().to_vec())
+            })
+            .collect()
+    }
+
+    fn part_one(numbers: &Vec<Vec<u8>>, _: &()) -> Result<u64> {
+        let digits = numbers.first().map_or(0, |s| s.len());
+        let mut ones = vec![0usize; digits];
+        let mut total = 0;
+        for number in numbers {
+            for (i, &c) in number.iter().enumerate() {
+                if c == b'1' {
+                    ones[i] += 1;
+                }
+            }
+            total += 1;
+        }
+
+        let mut gamma = vec![b'0'; digits];
+        for digit in 0..digits {
+            let ones_freq = ones[digit];
+            let zero_freq = total - ones_freq;
+            // A digit with as many ones as zeros has no most common bit; it's left as a zero.
+            if ones_freq > zero_freq {
+                gamma[digit] = b'1';
+            }
+        }
+        // The epsilon rate has the least common bits, which are the ones gamma doesn't have.
+        let epsilon: Vec<u8> = gamma.iter().map(|&c| b'0' + b'1' - c).collect();
+        let gamma_rate = rate(&gamma)?;
+        let epsilon_rate = rate(&epsilon)?;
+
+        gamma_rate
+            .checked_mul(epsilon_rate)
+            .ok_or_else(|| eyre!("the power consumption is too big to count"))
+    }
+
+    fn part_two(numbers: &Vec<Vec<u8>>, _: &()) -> Result<u64> {
+        let candidates: Vec<_> = numbers.iter().map(|s| s.as_slice()).collect();
+
+        let oxygen = rate(filter_bits(candidates.clone(), b'1')?)?;
+        let co2 = rate(filter_bits(candidates, b'0')?)?;
+
+        oxygen
+            .checked_mul(co2)
+            .ok_or_else(|| eyre!("the life support rating is too big to count"))
+    }
+}
+
+pub const EXAMPLE: &str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
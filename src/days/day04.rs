@@ -0,0 +1,152 @@
+use color_eyre::eyre::{eyre, Result};
+
+use crate::grid::Grid;
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Clone)]
+struct Board {
+    numbers: Grid<u32>,
+    marks: Grid<bool>,
+}
+
+impl Board {
+    fn read<'a>(text: &Text<'a>, input: &mut impl Iterator<Item = &'a str>) -> Result<Board> {
+        let mut board = Board {
+            numbers: Grid::new(0, 5, 0, 5),
+            marks: Grid::new(0, 5, 0, 5),
+        };
+
+        for row in board.numbers.rows_mut() {
+            let line = input
+                .next()
+                .ok_or_else(|| text.error(text.end(), "expected another row of the board"))?;
+            let numbers: Vec<_> = line.split(" ").filter(|s| !s.is_empty()).collect();
+            if numbers.len() != 5 {
+                return Err(text.error(line, "expected 5 numbers in the row").into());
+            }
+            for (cell, s) in row.iter_mut().zip(numbers) {
+                *cell = text.record(s)?;
+            }
+        }
+
+        Ok(board)
+    }
+
+    fn draw(&mut self, n: u32) {
+        for (number, mark) in self.numbers.map.iter().zip(&mut self.marks.map) {
+            if *number == n {
+                *mark = true;
+            }
+        }
+    }
+
+    fn has_won(&self) -> bool {
+        self.marks
+            .rows()
+            .any(|row| row.iter().all(|&marked| marked))
+            || self
+                .marks
+                .columns()
+                .any(|mut column| column.all(|&marked| marked))
+    }
+
+    fn score(&self) -> u32 {
+        self.numbers
+            .map
+            .iter()
+            .zip(&self.marks.map)
+            .filter(|&(_, &marked)| !marked)
+            .map(|(number, _)| number)
+            .sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Bingo {
+    draw_order: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+/// Plays every board to completion, returning the final scores in the order the boards won.
+fn play(bingo: &Bingo) -> Vec<u32> {
+    let mut boards = bingo.boards.clone();
+    let mut scores = Vec::with_capacity(boards.len());
+    for draw in &bingo.draw_order {
+        for board in boards.iter_mut() {
+            if board.has_won() {
+                continue;
+            }
+            board.draw(*draw);
+            if board.has_won() {
+                scores.push(board.score() * draw);
+            }
+        }
+    }
+    scores
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Bingo;
+    type Options = ();
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Bingo> {
+        let text = Text::new(Self::DAY, input);
+        let mut lines = text.lines();
+
+        let draw_order = text.comma_separated(lines.next().unwrap_or_default())?;
+
+        let mut boards = Vec::new();
+        while let Some(line) = lines.next() {
+            if !line.is_empty() {
+                return Err(text
+                    .error(line, "expected a blank line between boards")
+                    .into());
+            }
+            boards.push(Board::read(&text, &mut lines)?);
+        }
+
+        Ok(Bingo { draw_order, boards })
+    }
+
+    fn part_one(bingo: &Bingo, _: &()) -> Result<u32> {
+        play(bingo)
+            .first()
+            .copied()
+            .ok_or_else(|| eyre!("no board won"))
+    }
+
+    fn part_two(bingo: &Bingo, _: &()) -> Result<u32> {
+        play(bingo)
+            .last()
+            .copied()
+            .ok_or_else(|| eyre!("no board won"))
+    }
+}
+
+pub const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
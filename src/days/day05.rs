@@ -0,0 +1,166 @@
+use color_eyre::eyre::Result;
+use reformation::Reformation;
+
+use crate::parse::Text;
+use crate::Solution;
+
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Reformation)]
+#[reformation("{x1},{y1} -> {x2},{y2}")]
+pub struct Line {
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+}
+
+impl Line {
+    fn is_horizontal(&self) -> bool {
+        self.x1 == self.x2
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.y1 == self.y2
+    }
+}
+
+struct World {
+    x_min: i32,
+    x_len: i32,
+    y_min: i32,
+    y_len: i32,
+    map: Vec<usize>,
+}
+
+impl World {
+    fn new(x_min: i32, x_len: i32, y_min: i32, y_len: i32) -> World {
+        let map = vec![0; (x_len * y_len) as usize];
+        World {
+            x_min,
+            x_len,
+            y_min,
+            y_len,
+            map,
+        }
+    }
+
+    fn map_index(&self, (x, y): (i32, i32)) -> usize {
+        assert!(x >= self.x_min);
+        assert!(y >= self.y_min);
+
+        let x = x - self.x_min;
+        let y = y - self.y_min;
+
+        assert!(x < self.x_len);
+        assert!(y < self.y_len);
+
+        (y * self.x_len + x) as usize
+    }
+
+    fn record_line(&mut self, line: &Line) {
+        let mut x = line.x1;
+        let x_step = match line.x2 {
+            x2 if x2 > x => 1,
+            x2 if x2 < x => -1,
+            _ => 0,
+        };
+
+        let mut y = line.y1;
+        let y_step = match line.y2 {
+            y2 if y2 > y => 1,
+            y2 if y2 < y => -1,
+            _ => 0,
+        };
+
+        let x_len = line.x1.max(line.x2) - line.x1.min(line.x2) + 1;
+        let y_len = line.y1.max(line.y2) - line.y1.min(line.y2) + 1;
+        let steps = x_len.max(y_len);
+
+        for _ in 0..steps {
+            self[(x, y)] += 1;
+            x += x_step;
+            y += y_step;
+        }
+    }
+
+    fn intersections(&self) -> usize {
+        self.map.iter().map(|&c| if c >= 2 { 1 } else { 0 }).sum()
+    }
+}
+
+impl Index<(i32, i32)> for World {
+    type Output = usize;
+    fn index(&self, coords: (i32, i32)) -> &Self::Output {
+        &self.map[self.map_index(coords)]
+    }
+}
+
+impl IndexMut<(i32, i32)> for World {
+    fn index_mut(&mut self, coords: (i32, i32)) -> &mut Self::Output {
+        let index = self.map_index(coords);
+        &mut self.map[index]
+    }
+}
+
+fn intersections<'a>(lines: impl Iterator<Item = &'a Line> + Clone) -> usize {
+    let mut x_min = i32::MAX;
+    let mut x_max = i32::MIN;
+    let mut y_min = i32::MAX;
+    let mut y_max = i32::MIN;
+    for line in lines.clone() {
+        x_min = x_min.min(line.x1).min(line.x2);
+        x_max = x_max.max(line.x1).max(line.x2);
+        y_min = y_min.min(line.y1).min(line.y2);
+        y_max = y_max.max(line.y1).max(line.y2);
+    }
+    if x_min > x_max {
+        // No lines at all, like part one when every vent line is diagonal.
+        return 0;
+    }
+
+    let mut world = World::new(x_min, x_max - x_min + 1, y_min, y_max - y_min + 1);
+    for line in lines {
+        world.record_line(line);
+    }
+    world.intersections()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<Line>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Line>> {
+        Ok(Text::new(Self::DAY, input).records(input)?)
+    }
+
+    fn part_one(lines: &Vec<Line>, _: &()) -> Result<usize> {
+        Ok(intersections(
+            lines
+                .iter()
+                .filter(|line| line.is_horizontal() || line.is_vertical()),
+        ))
+    }
+
+    fn part_two(lines: &Vec<Line>, _: &()) -> Result<usize> {
+        Ok(intersections(lines.iter()))
+    }
+}
+
+pub const EXAMPLE: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
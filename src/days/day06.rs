@@ -0,0 +1,80 @@
+use color_eyre::eyre::{eyre, Result};
+
+use crate::parse::Text;
+use crate::solution::{self, Override};
+use crate::Solution;
+
+fn simulate(population: &[usize; 9], days: usize) -> Result<usize> {
+    let too_many = || eyre!("more than {} lanternfish after {} days", usize::MAX, days);
+    let mut population = *population;
+    for _ in 0..days {
+        population[..].rotate_left(1);
+        population[6] = population[6]
+            .checked_add(population[8])
+            .ok_or_else(too_many)?;
+    }
+    population
+        .iter()
+        .try_fold(0usize, |total, &count| total.checked_add(count))
+        .ok_or_else(too_many)
+}
+
+/// How long the lanternfish breed for.
+#[derive(Debug, Default)]
+pub struct Options {
+    /// 80 days in part one and 256 in part two.
+    days: Override<usize>,
+}
+
+impl solution::Options for Options {
+    const NAMES: &'static [(&'static str, &'static str)] = &[(
+        "days",
+        "how many days to simulate (80 for part one, 256 for part two)",
+    )];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        self.days.set(name, value)
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    // index = timer value
+    type Input = [usize; 9];
+    type Options = Options;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<[usize; 9]> {
+        let text = Text::new(Self::DAY, input);
+        let mut population = [0usize; 9];
+        for (i, timer) in text
+            .comma_separated::<usize>(input)?
+            .into_iter()
+            .enumerate()
+        {
+            match population.get_mut(timer) {
+                Some(count) => *count += 1,
+                None => {
+                    let at = input.split(",").nth(i).unwrap_or(input);
+                    return Err(text.error(at, "timers only go up to 8").into());
+                }
+            }
+        }
+        Ok(population)
+    }
+
+    fn part_one(population: &[usize; 9], options: &Options) -> Result<usize> {
+        simulate(population, options.days.or(80))
+    }
+
+    fn part_two(population: &[usize; 9], options: &Options) -> Result<usize> {
+        simulate(population, options.days.or(256))
+    }
+}
+
+pub const EXAMPLE: &str = "3,4,3,1,2";
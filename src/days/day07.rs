@@ -0,0 +1,90 @@
+use color_eyre::eyre::{bail, eyre, Result};
+
+use crate::parse::Text;
+use crate::solution::{self, option_value};
+use crate::Solution;
+
+use std::ops::Range;
+
+/// Finds the least fuel needed to line every crab up, given the fuel cost of moving a distance,
+/// or `None` if the cost doesn't fit in a `u64`.
+fn least_fuel(
+    population: &[i32],
+    positions: Range<i32>,
+    cost: impl Fn(u64) -> Option<u64>,
+) -> Result<u64> {
+    let mut best_fuel = u64::MAX;
+    for target in positions {
+        let fuel = population
+            .iter()
+            .map(|&start| start.abs_diff(target).into()) // compute distance
+            .try_fold(0u64, |fuel, distance| fuel.checked_add(cost(distance)?))
+            .ok_or_else(|| eyre!("too much fuel to count to line the crabs up at {}", target))?;
+        best_fuel = best_fuel.min(fuel);
+    }
+    Ok(best_fuel)
+}
+
+/// Where the crabs might line up. Both parts search `0..2000` by default, which covers the real
+/// input's crabs.
+#[derive(Debug)]
+pub struct Options {
+    positions: Range<i32>,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options { positions: 0..2000 }
+    }
+}
+
+impl solution::Options for Options {
+    const NAMES: &'static [(&'static str, &'static str)] = &[(
+        "positions",
+        "the range of positions to try lining the crabs up at, like 0..2000",
+    )];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let (start, end) = value
+            .split_once("..")
+            .ok_or_else(|| eyre!("bad range {:?} for option {}", value, name))?;
+        self.positions = option_value(name, start)?..option_value(name, end)?;
+        if self.positions.is_empty() {
+            bail!(
+                "option {} needs at least one position, not {:?}",
+                name,
+                value
+            );
+        }
+        Ok(())
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<i32>;
+    type Options = Options;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        Ok(Text::new(Self::DAY, input).comma_separated(input)?)
+    }
+
+    fn part_one(population: &Vec<i32>, options: &Options) -> Result<u64> {
+        least_fuel(population, options.positions.clone(), Some)
+    }
+
+    fn part_two(population: &Vec<i32>, options: &Options) -> Result<u64> {
+        // fuel used over distance
+        least_fuel(population, options.positions.clone(), |distance| {
+            Some(distance.checked_mul(distance + 1)? / 2)
+        })
+    }
+}
+
+pub const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
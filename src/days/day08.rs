@@ -0,0 +1,124 @@
+use color_eyre::eyre::Result;
+use itertools::Itertools;
+
+use crate::parse::Text;
+use crate::Solution;
+
+/// Each pattern is a bitmask of the segments it lights, with segment `a` in bit 0.
+#[derive(Debug)]
+pub struct Entry {
+    patterns: Vec<u8>,
+    outputs: Vec<u8>,
+}
+
+fn segments(patterns: &str) -> Vec<u8> {
+    patterns
+        .split_whitespace()
+        .map(|p| p.bytes().map(|c| 1u8 << (c - b'a')).sum())
+        .collect()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<Entry>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Entry>> {
+        let text = Text::new(Self::DAY, input);
+        input
+            .split("\n")
+            .map(|entry| {
+                let (patterns, outputs) = entry.split_once("|").ok_or_else(|| {
+                    text.error(entry, "expected a `|` between patterns and outputs")
+                })?;
+                if let Some(i) = entry.find(|c| !matches!(c, 'a'..='g' | ' ' | '|')) {
+                    return Err(text
+                        .error(&entry[i..], "expected a segment from a to g")
+                        .into());
+                }
+                let mut patterns = segments(patterns);
+                patterns.sort();
+                Ok(Entry {
+                    patterns,
+                    outputs: segments(outputs),
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(entries: &Vec<Entry>, _: &()) -> Result<usize> {
+        let mut answer = 0;
+        for entry in entries {
+            answer += entry
+                .outputs
+                .iter()
+                .map(|s| match s.count_ones() {
+                    2 => 1, // '1' digit
+                    4 => 1, // '4' digit
+                    3 => 1, // '7' digit
+                    7 => 1, // '8' digit
+                    _ => 0,
+                })
+                .sum::<usize>();
+        }
+        Ok(answer)
+    }
+
+    fn part_two(entries: &Vec<Entry>, _: &()) -> Result<usize> {
+        let mut answer = 0;
+        for entry in entries {
+            for wiring_pattern in (0..7).permutations(7) {
+                let masks: Vec<_> = wiring_pattern.iter().map(|n| 1u8 << n).collect();
+
+                let expected_patterns = [
+                    masks[0] | masks[1] | masks[2] | masks[4] | masks[5] | masks[6], // 0
+                    masks[2] | masks[5],                                             // 1
+                    masks[0] | masks[2] | masks[3] | masks[4] | masks[6],            // 2
+                    masks[0] | masks[2] | masks[3] | masks[5] | masks[6],            // 3
+                    masks[1] | masks[2] | masks[3] | masks[5],                       // 4
+                    masks[0] | masks[1] | masks[3] | masks[5] | masks[6],            // 5
+                    masks[0] | masks[1] | masks[3] | masks[4] | masks[5] | masks[6], // 6
+                    masks[0] | masks[2] | masks[5],                                  // 7
+                    masks[0] | masks[1] | masks[2] | masks[3] | masks[4] | masks[5] | masks[6], // 8
+                    masks[0] | masks[1] | masks[2] | masks[3] | masks[5] | masks[6], // 9
+                ];
+
+                let mut compare_patterns = expected_patterns;
+                compare_patterns.sort();
+                if entry.patterns == compare_patterns {
+                    let mut value = 0;
+                    for digit in &entry.outputs {
+                        value *= 10;
+                        value += expected_patterns
+                            .iter()
+                            .enumerate()
+                            .find(|(_, &p)| p == *digit)
+                            .unwrap()
+                            .0;
+                    }
+                    answer += value;
+                    break;
+                }
+            }
+        }
+        Ok(answer)
+    }
+}
+
+pub const EXAMPLE: &str =
+    "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
@@ -0,0 +1,56 @@
+use color_eyre::eyre::{bail, Result};
+
+use crate::grid::{Connectivity, Grid};
+use crate::parse::Text;
+use crate::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Grid<u32>;
+    type Options = ();
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Grid<u32>> {
+        let text = Text::new(Self::DAY, input);
+        Ok(Grid::parse(&text, input, |c| {
+            c.to_digit(10).ok_or("expected a digit")
+        })?)
+    }
+
+    fn part_one(world: &Grid<u32>, _: &()) -> Result<u32> {
+        let mut answer = 0;
+        for coords in world.cells() {
+            let h = world[coords];
+            if world
+                .neighbors4_of(coords)
+                .all(|(_, &neighbor)| neighbor > h)
+            {
+                answer += 1 + h;
+            }
+        }
+        Ok(answer)
+    }
+
+    fn part_two(world: &Grid<u32>, _: &()) -> Result<usize> {
+        // Basins are separated by ridges of 9s.
+        let basins = world.regions(Connectivity::Four, |&h| h != 9);
+        let mut sizes: Vec<_> = basins.regions.iter().map(|basin| basin.size).collect();
+        sizes.sort();
+        sizes.reverse();
+        match sizes[..] {
+            [a, b, c, ..] => Ok(a * b * c),
+            _ => bail!("need at least three basins, but there are {}", sizes.len()),
+        }
+    }
+}
+
+pub const EXAMPLE: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
@@ -0,0 +1,132 @@
+use color_eyre::eyre::Result;
+
+use crate::parse::Text;
+use crate::Solution;
+
+fn opener_for(c: char) -> char {
+    match c {
+        ')' => '(',
+        ']' => '[',
+        '}' => '{',
+        '>' => '<',
+        _ => panic!("unexpected character {}", c),
+    }
+}
+
+fn closer_for(c: char) -> char {
+    match c {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => panic!("unexpected character {}", c),
+    }
+}
+
+fn illegal_points(c: char) -> i64 {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("unexpected character {}", c),
+    }
+}
+
+fn complete_points(c: char) -> i64 {
+    match c {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => panic!("unexpected character {}", c),
+    }
+}
+
+enum Line {
+    Corrupted(i64),
+    Incomplete(i64),
+}
+
+fn check(line: &str) -> Line {
+    let mut stack = Vec::new();
+    for c in line.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' | ']' | '}' | '>' => {
+                if let Some(&start) = stack.last() {
+                    if start == opener_for(c) {
+                        stack.pop();
+                        continue;
+                    }
+                }
+                return Line::Corrupted(illegal_points(c));
+            }
+            _ => panic!("unexpected character {}", c),
+        }
+    }
+
+    // It's incomplete, affects part two.
+    let mut score = 0;
+    for c in stack.iter().rev() {
+        score = score * 5 + complete_points(closer_for(*c));
+    }
+    Line::Incomplete(score)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<String>;
+    type Options = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        let text = Text::new(Self::DAY, input);
+        input
+            .split("\n")
+            .map(|line| match line.find(|c| !"()[]{}<>".contains(c)) {
+                Some(i) => Err(text.error(&line[i..], "expected a bracket").into()),
+                None => Ok(line.to_owned()),
+            })
+            .collect()
+    }
+
+    fn part_one(lines: &Vec<String>, _: &()) -> Result<i64> {
+        Ok(lines
+            .iter()
+            .filter_map(|line| match check(line) {
+                Line::Corrupted(points) => Some(points),
+                Line::Incomplete(_) => None,
+            })
+            .sum())
+    }
+
+    fn part_two(lines: &Vec<String>, _: &()) -> Result<i64> {
+        let mut scores: Vec<_> = lines
+            .iter()
+            .filter_map(|line| match check(line) {
+                Line::Corrupted(_) => None,
+                Line::Incomplete(score) => Some(score),
+            })
+            .collect();
+
+        scores.sort_unstable();
+        Ok(scores[scores.len() / 2])
+    }
+}
+
+pub const EXAMPLE: &str = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
@@ -0,0 +1,99 @@
+use color_eyre::eyre::{eyre, Result};
+
+use crate::grid::{Automaton, Grid};
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Octopus {
+    Charging(u8),
+    Flashed,
+}
+
+impl Default for Octopus {
+    fn default() -> Self {
+        Octopus::Charging(0)
+    }
+}
+
+/// Runs one step of the simulation, returning how many octopuses flashed.
+fn step(octopuses: &mut Automaton<Octopus>) -> usize {
+    // Everyone gains 1 charge to start.
+    octopuses.step(|grid, cell| match grid[cell] {
+        Octopus::Charging(c) => Octopus::Charging(c + 1),
+        Octopus::Flashed => Octopus::Flashed,
+    });
+
+    // Anyone who's exceeded 9 charge flashes, which adds one to all their neighbors' charge,
+    // until nobody else does.
+    let flashing = |octopus| matches!(octopus, Octopus::Charging(c) if c > 9);
+    octopuses.settle(None, |grid, cell| match grid[cell] {
+        octopus if flashing(octopus) => Octopus::Flashed,
+        Octopus::Charging(c) => {
+            let flashes = grid
+                .neighbors8_of(cell)
+                .filter(|&(_, &octopus)| flashing(octopus))
+                .count();
+            Octopus::Charging(c + flashes as u8)
+        }
+        Octopus::Flashed => Octopus::Flashed,
+    });
+
+    // Finally, reset all flashed octopuses to 0.
+    octopuses.step(|grid, cell| match grid[cell] {
+        Octopus::Flashed => Octopus::Charging(0),
+        charging => charging,
+    })
+}
+
+/// How long to wait for the octopuses to all flash at once. Some grids never get in step.
+const STEP_LIMIT: usize = 10_000;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Grid<Octopus>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Grid<Octopus>> {
+        let text = Text::new(Self::DAY, input);
+        Ok(Grid::parse(&text, input, |c| match c.to_digit(10) {
+            Some(charge) => Ok(Octopus::Charging(charge as u8)),
+            None => Err("expected a digit"),
+        })?)
+    }
+
+    fn part_one(grid: &Grid<Octopus>, _: &()) -> Result<usize> {
+        let mut octopuses = Automaton::new(grid.clone());
+        Ok((0..100).map(|_| step(&mut octopuses)).sum())
+    }
+
+    fn part_two(grid: &Grid<Octopus>, _: &()) -> Result<usize> {
+        let mut octopuses = Automaton::new(grid.clone());
+        let everyone = grid.map.len();
+        (1..=STEP_LIMIT)
+            .find(|_| step(&mut octopuses) == everyone)
+            .ok_or_else(|| {
+                eyre!(
+                    "the octopuses don't all flash at once in {} steps",
+                    STEP_LIMIT
+                )
+            })
+    }
+}
+
+pub const EXAMPLE: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
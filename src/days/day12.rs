@@ -0,0 +1,154 @@
+use color_eyre::eyre::Result;
+use reformation::Reformation;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Reformation)]
+#[reformation(r"{from}-{to}")]
+struct Edge {
+    from: String,
+    to: String,
+}
+
+#[derive(Debug)]
+pub struct Room {
+    big: bool,
+    endpoint: bool,
+    neighbors: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Path {
+    path: Vec<String>,
+    visited: HashSet<String>,
+    double_small: bool,
+}
+
+impl Path {
+    fn start() -> Path {
+        Path {
+            path: vec!["start".to_owned()],
+            visited: vec!["start".to_owned()].into_iter().collect(),
+            double_small: false,
+        }
+    }
+
+    fn extend(&self, room: &str) -> Path {
+        let mut path = self.clone();
+        path.path.push(room.to_owned());
+        path.visited.insert(room.to_owned());
+        path
+    }
+
+    fn current_room(&self) -> &str {
+        self.path.last().unwrap()
+    }
+
+    fn has_visited(&self, room: &str) -> bool {
+        self.visited.contains(room)
+    }
+}
+
+impl Room {
+    fn new(name: String) -> Self {
+        Self {
+            big: name.chars().all(|c| c.is_uppercase()),
+            endpoint: false,
+            neighbors: Vec::new(),
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = HashMap<String, Room>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<HashMap<String, Room>> {
+        let text = Text::new(Self::DAY, input);
+        let mut map: HashMap<String, Room> = HashMap::new();
+        for edge in text.records::<Edge>(input)? {
+            map.entry(edge.from.clone())
+                .or_insert_with(|| Room::new(edge.from.clone()))
+                .neighbors
+                .push(edge.to.clone());
+
+            map.entry(edge.to.clone())
+                .or_insert_with(|| Room::new(edge.to))
+                .neighbors
+                .push(edge.from);
+        }
+        for endpoint in ["start", "end"] {
+            map.get_mut(endpoint)
+                .ok_or_else(|| text.error(text.end(), format!("no cave is called {}", endpoint)))?
+                .endpoint = true;
+        }
+        Ok(map)
+    }
+
+    fn part_one(map: &HashMap<String, Room>, _: &()) -> Result<usize> {
+        let mut valid_paths = Vec::new();
+        let mut all_paths = vec![Path::start()];
+        while let Some(path) = all_paths.pop() {
+            let this_room_name = path.current_room();
+            if this_room_name == "end" {
+                valid_paths.push(path);
+                continue;
+            }
+
+            let this_room = map.get(this_room_name).unwrap();
+            for next_room_name in &this_room.neighbors {
+                let next_room = map.get(next_room_name).unwrap();
+                if next_room.big || !path.has_visited(next_room_name) {
+                    all_paths.push(path.extend(next_room_name));
+                }
+            }
+        }
+        Ok(valid_paths.len())
+    }
+
+    fn part_two(map: &HashMap<String, Room>, _: &()) -> Result<usize> {
+        let mut valid_paths = Vec::new();
+        let mut all_paths = vec![Path::start()];
+        while let Some(path) = all_paths.pop() {
+            let this_room_name = path.current_room();
+            if this_room_name == "end" {
+                valid_paths.push(path);
+                continue;
+            }
+
+            let this_room = map.get(this_room_name).unwrap();
+            for next_room_name in &this_room.neighbors {
+                let next_room = map.get(next_room_name).unwrap();
+                if next_room.big
+                    || (!path.double_small && !next_room.endpoint)
+                    || !path.has_visited(next_room_name)
+                {
+                    let mut new_path = path.extend(next_room_name);
+                    if !next_room.big && path.has_visited(next_room_name) {
+                        new_path.double_small = true;
+                    }
+                    all_paths.push(new_path);
+                }
+            }
+        }
+        Ok(valid_paths.len())
+    }
+}
+
+pub const EXAMPLE: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
@@ -0,0 +1,109 @@
+use color_eyre::eyre::Result;
+use reformation::Reformation;
+
+use crate::grid::BitGrid;
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Reformation)]
+#[reformation("{},{}")]
+struct Dot(usize, usize);
+
+#[derive(Debug, Reformation)]
+#[reformation("fold along {}={}")]
+pub struct Fold(char, usize);
+
+#[derive(Debug)]
+pub struct Manual {
+    paper: BitGrid,
+    folds: Vec<Fold>,
+}
+
+/// Draws the part of the paper with dots on it.
+fn render_paper(paper: &BitGrid) -> String {
+    let Some(bounds) = paper.bounds() else {
+        return String::new();
+    };
+    let paper = paper.to_grid().crop(bounds);
+    paper.render(|&dot| if dot { '#' } else { '.' }).to_string()
+}
+
+fn fold_paper(paper: &mut BitGrid, fold: &Fold) {
+    match fold.0 {
+        'y' => paper.fold_y(fold.1),
+        _ => paper.fold_x(fold.1),
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Manual;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Manual> {
+        let text = Text::new(Self::DAY, input);
+        let (dots, folds) = text.two_sections(input, "the folds")?;
+
+        let dots = text.records::<Dot>(dots)?;
+        let width = dots.iter().map(|dot| dot.0 + 1).max().unwrap_or(0);
+        let height = dots.iter().map(|dot| dot.1 + 1).max().unwrap_or(0);
+        let mut paper = BitGrid::new(width, height);
+        for dot in dots {
+            paper.set(dot.0, dot.1, true);
+        }
+
+        let folds = folds
+            .split("\n")
+            .map(|line| match text.record::<Fold>(line)? {
+                fold if fold.0 == 'x' || fold.0 == 'y' => Ok(fold),
+                _ => Err(text.error(line, "can only fold along x or y")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Manual { paper, folds })
+    }
+
+    fn part_one(manual: &Manual, _: &()) -> Result<usize> {
+        let mut paper = manual.paper.clone();
+        if let Some(fold) = manual.folds.first() {
+            fold_paper(&mut paper, fold);
+        }
+        Ok(paper.count_ones())
+    }
+
+    fn part_two(manual: &Manual, _: &()) -> Result<String> {
+        let mut paper = manual.paper.clone();
+        for fold in &manual.folds {
+            fold_paper(&mut paper, fold);
+        }
+        Ok(render_paper(&paper))
+    }
+}
+
+pub const EXAMPLE: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
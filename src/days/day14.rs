@@ -0,0 +1,161 @@
+use color_eyre::eyre::Result;
+use reformation::Reformation;
+
+use crate::parse::Text;
+use crate::solution::{self, Override};
+use crate::Solution;
+
+use std::collections::HashMap;
+
+#[derive(Reformation)]
+#[reformation("{first}{second} -> {insertion}")]
+struct Rule {
+    first: char,
+    second: char,
+    insertion: char,
+}
+
+#[derive(Debug)]
+pub struct Polymer {
+    template: Vec<u8>,
+    rules: HashMap<[u8; 2], u8>,
+}
+
+fn polymerize(polymer: &Polymer, steps: usize) -> u64 {
+    let Polymer { template, rules } = polymer;
+
+    let mut pairs: HashMap<[u8; 2], u64> = HashMap::new();
+    for pair in template.windows(2) {
+        *pairs.entry(pair.try_into().unwrap()).or_insert(0) += 1;
+    }
+
+    for _ in 0..steps {
+        let mut next = HashMap::new();
+        for (pair, count) in pairs {
+            // NN + (NN -> C) => NC + CN
+            let inserted = *rules.get(&pair).unwrap();
+            let left_pair = [pair[0], inserted];
+            let right_pair = [inserted, pair[1]];
+            *next.entry(left_pair).or_insert(0) += count;
+            *next.entry(right_pair).or_insert(0) += count;
+        }
+        pairs = next;
+    }
+
+    let mut frequencies = HashMap::new();
+    for (pair, count) in &pairs {
+        *frequencies.entry(pair[0]).or_insert(0) += count;
+        // don't take the second one, they all show up twice except for the very last character...
+    }
+    // ...so take care of the last character separately
+    *frequencies.entry(*template.last().unwrap()).or_insert(0) += 1;
+
+    let most_common = frequencies.values().max().unwrap();
+    let least_common = frequencies.values().min().unwrap();
+    most_common - least_common
+}
+
+/// How many rounds of pair insertion to run.
+#[derive(Debug, Default)]
+pub struct Options {
+    /// Ten rounds for part one and forty for part two.
+    steps: Override<usize>,
+}
+
+impl solution::Options for Options {
+    const NAMES: &'static [(&'static str, &'static str)] = &[(
+        "steps",
+        "how many steps of pair insertion to run (10 for part one, 40 for part two)",
+    )];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        self.steps.set(name, value)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Polymer;
+    type Options = Options;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Polymer> {
+        let text = Text::new(Self::DAY, input);
+        let (template, rules) = text.two_sections(input, "the insertion rules")?;
+
+        if template.is_empty() {
+            return Err(text.error(template, "expected a polymer template").into());
+        }
+        if let Some(i) = template.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(text
+                .error(&template[i..], "expected an element letter")
+                .into());
+        }
+        let lines: Vec<&str> = rules.split("\n").collect();
+        let parsed = text.records::<Rule>(rules)?;
+        for (line, rule) in lines.iter().zip(&parsed) {
+            if ![rule.first, rule.second, rule.insertion]
+                .iter()
+                .all(char::is_ascii_alphabetic)
+            {
+                return Err(text.error(line, "expected element letters").into());
+            }
+        }
+
+        let rules: HashMap<[u8; 2], u8> = parsed
+            .iter()
+            .map(|rule| ([rule.first as u8, rule.second as u8], rule.insertion as u8))
+            .collect();
+
+        // Every pair that can ever turn up needs a rule, or there'd be nothing to insert into it.
+        let missing = |pair: &[u8]| !rules.contains_key(pair);
+        if let Some(i) = template.as_bytes().windows(2).position(missing) {
+            let at = &template[i..i + 2];
+            return Err(text.error(at, "no insertion rule for this pair").into());
+        }
+        for (line, rule) in lines.iter().zip(&parsed) {
+            let [first, second, inserted] =
+                [rule.first, rule.second, rule.insertion].map(|c| c as u8);
+            if missing(&[first, inserted]) || missing(&[inserted, second]) {
+                return Err(text
+                    .error(line, "inserts a pair that has no rule of its own")
+                    .into());
+            }
+        }
+
+        let template = template.bytes().collect();
+        Ok(Polymer { template, rules })
+    }
+
+    fn part_one(polymer: &Polymer, options: &Options) -> Result<u64> {
+        Ok(polymerize(polymer, options.steps.or(10)))
+    }
+
+    fn part_two(polymer: &Polymer, options: &Options) -> Result<u64> {
+        Ok(polymerize(polymer, options.steps.or(40)))
+    }
+}
+
+pub const EXAMPLE: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
@@ -0,0 +1,63 @@
+use color_eyre::eyre::Result;
+
+use crate::grid::{Connectivity, Grid};
+use crate::parse::Text;
+use crate::Solution;
+
+fn lowest_risk(grid: &Grid<usize>) -> usize {
+    let goal = (grid.x_len - 1, grid.y_len - 1);
+    // Every step risks at least 1, so the distance left never overestimates the risk.
+    let distance = |(x, y): (i32, i32)| (goal.0 - x + goal.1 - y) as usize;
+    grid.shortest_path_guided(
+        (0, 0),
+        goal,
+        Connectivity::Four,
+        |&risk| Some(risk),
+        distance,
+    )
+    .unwrap()
+    .cost
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Grid<usize>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Grid<usize>> {
+        let text = Text::new(Self::DAY, input);
+        Ok(Grid::parse(&text, input, |c| match c.to_digit(10) {
+            Some(risk) => Ok(risk as usize),
+            None => Err("expected a digit"),
+        })?)
+    }
+
+    fn part_one(grid: &Grid<usize>, _: &()) -> Result<usize> {
+        Ok(lowest_risk(grid))
+    }
+
+    fn part_two(tile: &Grid<usize>, _: &()) -> Result<usize> {
+        // Each tile right or down adds one to the risk, wrapping from 9 back round to 1.
+        let grid = tile.tile(5, 5, |x_tile, y_tile, &risk| {
+            (risk + (x_tile + y_tile) as usize - 1) % 9 + 1
+        });
+        Ok(lowest_risk(&grid))
+    }
+}
+
+pub const EXAMPLE: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
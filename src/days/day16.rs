@@ -0,0 +1,218 @@
+use color_eyre::eyre::Result;
+
+use bitvec::prelude::*;
+
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug)]
+pub struct Packet {
+    version: u8,
+    type_id: u8,
+    payload: Payload,
+}
+
+#[derive(Debug)]
+enum Payload {
+    Literal(u64),
+    Operator(Vec<Packet>),
+}
+
+type Bits = BitSlice<Msb0, u8>;
+
+/// Why the transmission doesn't make sense, and the bits where that became clear.
+type Invalid<'a> = (&'static str, &'a Bits);
+
+fn take(bits: &Bits, n: usize) -> Result<(&Bits, &Bits), Invalid<'_>> {
+    if bits.len() < n {
+        return Err(("the transmission ends in the middle of a packet", bits));
+    }
+    Ok(bits.split_at(n))
+}
+
+fn parse_packet(bits: &Bits) -> Result<(Packet, &Bits), Invalid<'_>> {
+    let (version, bits) = take(bits, 3)?;
+    let (type_id, bits) = take(bits, 3)?;
+    let type_id = type_id.load_be();
+
+    let (payload, tail) = match type_id {
+        4 => parse_literal(bits)?,
+        _ => parse_operator(bits)?,
+    };
+
+    if let Payload::Operator(subpackets) = &payload {
+        match (type_id, subpackets.len()) {
+            (2 | 3, 0) => return Err(("min and max packets need a subpacket", bits)),
+            (5..=7, n) if n != 2 => return Err(("comparison packets need two subpackets", bits)),
+            _ => (),
+        }
+    }
+
+    Ok((
+        Packet {
+            version: version.load_be(),
+            type_id,
+            payload,
+        },
+        tail,
+    ))
+}
+
+fn parse_literal(mut bits: &Bits) -> Result<(Payload, &Bits), Invalid<'_>> {
+    let mut value = 0u64;
+    let mut keep_going = true;
+    while keep_going {
+        let (group, tail) = take(bits, 5)?;
+        keep_going = group[0];
+        let word: u64 = group[1..5].load_be();
+        value = (value << 4) | word;
+        bits = tail;
+    }
+
+    Ok((Payload::Literal(value), bits))
+}
+
+fn parse_operator(bits: &Bits) -> Result<(Payload, &Bits), Invalid<'_>> {
+    let (length_type_id, bits) = take(bits, 1)?;
+    if length_type_id[0] {
+        parse_operator_by_number_of_subpackets(bits)
+    } else {
+        parse_operator_by_number_of_bits(bits)
+    }
+}
+
+fn parse_operator_by_number_of_subpackets(bits: &Bits) -> Result<(Payload, &Bits), Invalid<'_>> {
+    let (num_subpackets, mut bits) = take(bits, 11)?;
+    let num_subpackets: usize = num_subpackets.load_be();
+
+    let mut packets = Vec::with_capacity(num_subpackets);
+    for _ in 0..num_subpackets {
+        let (packet, tail) = parse_packet(bits)?;
+        packets.push(packet);
+        bits = tail;
+    }
+
+    Ok((Payload::Operator(packets), bits))
+}
+
+fn parse_operator_by_number_of_bits(bits: &Bits) -> Result<(Payload, &Bits), Invalid<'_>> {
+    let (bits_remaining, bits) = take(bits, 15)?;
+    let bits_remaining: usize = bits_remaining.load_be();
+    let (mut bits, tail) = take(bits, bits_remaining)?;
+
+    let mut packets = Vec::new();
+    while !bits.is_empty() {
+        let (packet, rest) = parse_packet(bits)?;
+        packets.push(packet);
+        bits = rest;
+    }
+
+    Ok((Payload::Operator(packets), tail))
+}
+
+fn version_sum(packets: &[Packet]) -> usize {
+    let mut sum = 0;
+    for packet in packets {
+        sum += usize::from(packet.version);
+        if let Payload::Operator(subpackets) = &packet.payload {
+            sum += version_sum(subpackets);
+        }
+    }
+    sum
+}
+
+fn evaluate(packet: &Packet) -> u64 {
+    if packet.type_id == 4 {
+        match packet.payload {
+            Payload::Literal(v) => return v,
+            _ => panic!("non-literal literal"),
+        }
+    }
+
+    let subpackets = match &packet.payload {
+        Payload::Operator(subpackets) => subpackets,
+        _ => panic!("non-operator operator"),
+    };
+
+    let mut values = subpackets.iter().map(evaluate);
+    match packet.type_id {
+        0 => values.sum(),
+        1 => values.product(),
+        2 => values.min().unwrap(),
+        3 => values.max().unwrap(),
+        5 => {
+            if values.next().unwrap() > values.next().unwrap() {
+                1
+            } else {
+                0
+            }
+        }
+        6 => {
+            if values.next().unwrap() < values.next().unwrap() {
+                1
+            } else {
+                0
+            }
+        }
+        7 => {
+            if values.next().unwrap() == values.next().unwrap() {
+                1
+            } else {
+                0
+            }
+        }
+        _ => panic!("illegal type id"),
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Packet;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Packet> {
+        let text = Text::new(Self::DAY, input);
+        let digits = input
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(16)
+                    .map(|d| d as u8)
+                    .ok_or_else(|| text.error(&input[i..], "expected a hexadecimal digit"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if digits.len() % 2 != 0 {
+            return Err(text
+                .error(text.end(), "expected a whole number of bytes")
+                .into());
+        }
+        let data: Vec<_> = digits
+            .chunks(2)
+            .map(|digits| (digits[0] << 4) | digits[1])
+            .collect();
+
+        let bits = data.view_bits::<Msb0>();
+
+        let (packet, _) = parse_packet(bits).map_err(|(reason, at)| {
+            let digit = (bits.len() - at.len()) / 4;
+            text.error(&input[digit..], reason)
+        })?;
+        Ok(packet)
+    }
+
+    fn part_one(packet: &Packet, _: &()) -> Result<usize> {
+        Ok(version_sum(std::slice::from_ref(packet)))
+    }
+
+    fn part_two(packet: &Packet, _: &()) -> Result<u64> {
+        Ok(evaluate(packet))
+    }
+}
+
+//const EXAMPLE: &'static str = "8A004A801A8002F478";
+pub const EXAMPLE: &str = "A0016C880162017C3686B18A3D4780";
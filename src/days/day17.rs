@@ -0,0 +1,92 @@
+use color_eyre::eyre::{eyre, Result};
+use reformation::Reformation;
+
+use crate::grid::Point;
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Reformation)]
+#[reformation("target area: x={x_min}..{x_max}, y={y_min}..{y_max}")]
+pub struct Target {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+}
+
+impl Target {
+    fn contains(&self, Point { x, y }: Point) -> bool {
+        x >= self.x_min && x <= self.x_max && y >= self.y_min && y <= self.y_max
+    }
+}
+
+fn step(pos: &mut Point, vel: &mut Point) {
+    *pos += *vel;
+    // Drag slows the probe towards no forward motion, and gravity pulls it down.
+    *vel -= Point::new(vel.x.signum(), 1);
+}
+
+#[derive(Debug)]
+struct Trajectory {
+    max_height: i32,
+}
+
+impl Trajectory {
+    fn simulate(initial_velocity: Point, target: &Target) -> Option<Trajectory> {
+        let mut pos = Point::ORIGIN;
+        let mut vel = initial_velocity;
+        let mut max_height = 0;
+
+        while !target.contains(pos) {
+            if pos.y < target.y_min {
+                return None;
+            }
+            step(&mut pos, &mut vel);
+            max_height = max_height.max(pos.y);
+        }
+
+        Some(Trajectory { max_height })
+    }
+}
+
+fn trajectories(target: &Target) -> Vec<Trajectory> {
+    let mut trajectories = Vec::new();
+    for x_vel in 0..=target.x_max {
+        for y_vel in target.y_min..1000 {
+            if let Some(t) = Trajectory::simulate(Point::new(x_vel, y_vel), target) {
+                trajectories.push(t);
+            }
+        }
+    }
+    trajectories
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Target;
+    type Options = ();
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Target> {
+        Ok(Text::new(Self::DAY, input).record(input)?)
+    }
+
+    fn part_one(target: &Target, _: &()) -> Result<i32> {
+        trajectories(target)
+            .iter()
+            .map(|t| t.max_height)
+            .max()
+            .ok_or_else(|| eyre!("no trajectory hits the target"))
+    }
+
+    fn part_two(target: &Target, _: &()) -> Result<usize> {
+        Ok(trajectories(target).len())
+    }
+}
+
+pub const EXAMPLE: &str = "target area: x=20..30, y=-10..-5";
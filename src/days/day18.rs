@@ -0,0 +1,266 @@
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use reformation::Reformation;
+
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Clone)]
+enum Element {
+    Number(u8),
+    Pair(Box<Snailfish>),
+}
+
+impl Element {
+    fn explode_pair(&mut self, depth: usize, index: usize) -> (Option<(u8, u8)>, usize) {
+        match self {
+            Element::Number(_) => (None, index + 1),
+            Element::Pair(fish) => {
+                let (exploded, explosion_index) = fish.explode_pair(depth + 1, index);
+                if depth == 3 {
+                    *self = Element::Number(0);
+                }
+                (exploded, explosion_index)
+            }
+        }
+    }
+
+    fn get_mut(&mut self, n: usize) -> (Option<&mut u8>, usize) {
+        match self {
+            Element::Number(v) if n == 0 => (Some(v), 1),
+            Element::Number(_) => (None, 1),
+            Element::Pair(fish) => fish.get_mut(n),
+        }
+    }
+
+    fn split(&mut self) -> bool {
+        match self {
+            Element::Number(v) if *v >= 10 => {
+                let left = *v / 2;
+                let right = *v - left;
+                let fish = Snailfish {
+                    left: Element::Number(left),
+                    right: Element::Number(right),
+                };
+                *self = Element::Pair(Box::new(fish));
+                true
+            }
+            Element::Number(_) => false,
+            Element::Pair(fish) => fish.split(),
+        }
+    }
+
+    fn magnitude(&self) -> usize {
+        match self {
+            Element::Number(n) => usize::from(*n),
+            Element::Pair(fish) => fish.magnitude(),
+        }
+    }
+}
+
+impl std::fmt::Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Element::Number(n) => write!(f, "{}", n),
+            Element::Pair(fish) => write!(f, "{}", fish),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Snailfish {
+    left: Element,
+    right: Element,
+}
+
+// [[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]
+//       _________
+//      /         \
+//     /\         /\
+//    3 /\       6 /\
+//     2 /\       5 /\
+//      1 /\       4 /\
+//       7  3       3  2
+//
+// [[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]
+//       _________
+//      /         \
+//     /\         /\
+//    3 /\       9 /\
+//     2 /\       5 /\
+//      8  0       4 /\
+//                  3  2
+
+impl Snailfish {
+    fn reduce(mut self) -> Self {
+        loop {
+            // Apply the first explosion, if any.
+            let (exploded, at_index) = self.explode_pair(0, 0);
+            if let Some((left, right)) = exploded {
+                if at_index > 0 {
+                    if let Some(v) = self.get_mut(at_index - 1).0 {
+                        *v += left;
+                    }
+                }
+                if let Some(v) = self.get_mut(at_index + 1).0 {
+                    *v += right;
+                }
+                continue;
+            }
+
+            // Then apply any splits, if there is on.
+            if self.split() {
+                continue;
+            }
+
+            // If we haven't hit any reduction condition by now, we're done.
+            break;
+        }
+
+        self
+    }
+
+    fn explode_pair(&mut self, depth: usize, index: usize) -> (Option<(u8, u8)>, usize) {
+        if depth == 4 {
+            return (
+                Some((self.left.magnitude() as u8, self.right.magnitude() as u8)),
+                index,
+            );
+        }
+
+        let (result, index_after_left) = self.left.explode_pair(depth, index);
+        if result.is_some() {
+            return (result, index_after_left);
+        }
+        self.right.explode_pair(depth, index_after_left)
+    }
+
+    // Accesses the value of a node given an in-order traversal index.
+    fn get_mut(&mut self, n: usize) -> (Option<&mut u8>, usize) {
+        let (value, left_visited) = self.left.get_mut(n);
+        if value.is_some() {
+            return (value, left_visited);
+        }
+        let (value, right_visited) = self.right.get_mut(n - left_visited);
+        (value, left_visited + right_visited)
+    }
+
+    fn split(&mut self) -> bool {
+        self.left.split() || self.right.split()
+    }
+
+    fn magnitude(&self) -> usize {
+        3 * self.left.magnitude() + 2 * self.right.magnitude()
+    }
+}
+
+impl std::iter::Sum for Snailfish {
+    fn sum<I>(mut iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        let mut fish = iter.next().unwrap();
+        for right in iter {
+            fish = Snailfish {
+                left: Element::Pair(Box::new(fish)),
+                right: Element::Pair(Box::new(right)),
+            }
+            .reduce();
+        }
+        fish
+    }
+}
+
+impl std::fmt::Display for Snailfish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{},{}]", self.left, self.right)
+    }
+}
+
+/// Why a snailfish number doesn't make sense, and where.
+type Invalid<'a> = (String, &'a str);
+
+fn expect(s: &str, e: char) -> Result<&str, Invalid<'_>> {
+    s.strip_prefix(e)
+        .ok_or_else(|| (format!("expected `{}`", e), s))
+}
+
+fn parse_element(s: &str) -> Result<(Element, &str), Invalid<'_>> {
+    if s.starts_with('[') {
+        let (fish, tail) = parse_snailfish(s)?;
+        return Ok((Element::Pair(Box::new(fish)), tail));
+    }
+
+    let end = s.find([',', ']']).unwrap_or(s.len());
+    let number = u8::parse(&s[..end]).map_err(|_| ("expected a number or a pair".to_owned(), s))?;
+    Ok((Element::Number(number), &s[end..]))
+}
+
+fn parse_snailfish(mut s: &str) -> Result<(Snailfish, &str), Invalid<'_>> {
+    s = expect(s, '[')?;
+
+    let (left, tail) = parse_element(s)?;
+    s = tail;
+
+    s = expect(s, ',')?;
+
+    let (right, tail) = parse_element(s)?;
+    s = tail;
+
+    s = expect(s, ']')?;
+
+    Ok((Snailfish { left, right }, s))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<Snailfish>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Snailfish>> {
+        let text = Text::new(Self::DAY, input);
+        Ok(input
+            .split("\n")
+            .map(|line| match parse_snailfish(line) {
+                Ok((fish, "")) => Ok(fish),
+                Ok((_, tail)) => Err(text.error(tail, "expected the end of the line")),
+                Err((reason, at)) => Err(text.error(at, reason)),
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_one(homework: &Vec<Snailfish>, _: &()) -> Result<usize> {
+        Ok(homework.iter().cloned().sum::<Snailfish>().magnitude())
+    }
+
+    fn part_two(homework: &Vec<Snailfish>, _: &()) -> Result<usize> {
+        homework
+            .iter()
+            .cloned()
+            .combinations(2)
+            .map(|c| {
+                let forward = c.iter().cloned().sum::<Snailfish>().magnitude();
+                let backward = c.iter().rev().cloned().sum::<Snailfish>().magnitude();
+                forward.max(backward)
+            })
+            .max()
+            .ok_or_else(|| eyre!("need at least two numbers"))
+    }
+}
+
+pub const EXAMPLE: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
@@ -0,0 +1,417 @@
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use nalgebra::{Matrix3, Vector3};
+use reformation::Reformation;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Reformation, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[reformation("{x},{y},{z}")]
+pub struct Coordinate {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Coordinate {
+    fn dist(&self) -> i32 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    fn as_vector3(&self) -> Vector3<i32> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    fn from_vector3(v: Vector3<i32>) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl std::ops::Sub for Coordinate {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coordinate {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+fn all_rotations() -> Vec<Matrix3<i32>> {
+    // Rotating 90 degrees around X axis:
+    //  - x stays the same
+    //  - y axis becomes new z axis
+    //  - z slides over to y axis, but is backward
+    const ROT_90_X: Matrix3<i32> = Matrix3::new(1, 0, 0, 0, 0, -1, 0, 1, 0);
+    // Rotating 90 degrees around Y axis:
+    //  - x axis becomes new z axis
+    //  - y stays the same
+    //  - z axis slides over to x axis, but is backward
+    const ROT_90_Y: Matrix3<i32> = Matrix3::new(0, 0, -1, 0, 1, 0, 1, 0, 0);
+    // Rotating 90 degrees around Z axis:
+    //  - x axis becomes new y axis
+    //  - y slides over to x axis, but is backward
+    //  - z stays the same
+    const ROT_90_Z: Matrix3<i32> = Matrix3::new(0, 1, 0, -1, 0, 0, 0, 0, 1);
+
+    let identity: Matrix3<i32> = Matrix3::identity();
+    // if we imagine the sensor 'looking out' the x-axis...
+    // compose all possible 'up' directions...
+    let up_rotations = (0..4)
+        .map(|n| {
+            // nalgebra's matrix 'power' doesn't like the i32s I guess
+            let mut rotation = identity;
+            for _ in 0..n {
+                rotation = ROT_90_X * rotation;
+            }
+            rotation
+        })
+        .collect_vec();
+    // ...with the x-axis being ±x or ±z...
+    let mut axis_rotations = (0..4)
+        .map(|n| {
+            let mut rotation = identity;
+            for _ in 0..n {
+                rotation = ROT_90_Y * rotation;
+            }
+            rotation
+        })
+        .collect_vec();
+    // ...or ±y.
+    axis_rotations.push(ROT_90_Z);
+    axis_rotations.push(ROT_90_Z * ROT_90_Z * ROT_90_Z);
+    up_rotations
+        .iter()
+        .cartesian_product(axis_rotations)
+        .map(|(a, b)| a * b)
+        .collect_vec()
+}
+
+/// Works out where every scanner is relative to scanner 0, returning the positions of the
+/// scanners and of the beacons each one saw.
+fn align(scanners: &[Vec<Coordinate>]) -> (Vec<Vector3<i32>>, Vec<Vec<Coordinate>>) {
+    let all_rotations = all_rotations();
+
+    // For every beacon observed by every scanner, compute the relative distance from that beacon
+    // to its neighbors. If there are 12 beacons that overlap between scanners, there should be at
+    // least 12 beacons that have matching 'distance' profiles.
+    let mut spacings = HashMap::new();
+    for (scanner_index, measurements) in scanners.iter().enumerate() {
+        for pair in measurements.iter().enumerate().combinations(2) {
+            let (beacon_index_a, &position_a) = pair[0];
+            let (beacon_index_b, &position_b) = pair[1];
+            let distance = (position_a - position_b).dist();
+            spacings
+                .entry((scanner_index, beacon_index_a))
+                .or_insert_with(HashSet::new)
+                .insert(distance);
+            spacings
+                .entry((scanner_index, beacon_index_b))
+                .or_insert_with(HashSet::new)
+                .insert(distance);
+        }
+    }
+
+    // We'll start building a map, and we'll just declare that scanner 0's frame is correct.
+    let mut scanner_world_positions = vec![None; scanners.len()];
+    scanner_world_positions[0] = Some(Vector3::new(0, 0, 0));
+    let mut beacon_world_positions = vec![None; scanners.len()];
+    beacon_world_positions[0] = Some(scanners[0].clone());
+
+    // As long as we have unaligned scanners...
+    let mut unaligned_scanners: VecDeque<usize> = (1..scanners.len()).collect();
+    'outer: while let Some(unaligned_scanner_index) = unaligned_scanners.pop_front() {
+        // Try to align to any scanners that we know the global position for.
+        for reference_scanner_index in 0..scanners.len() {
+            if beacon_world_positions[reference_scanner_index].is_none() {
+                // Nope, don't know this one yet...
+                continue;
+            }
+            let reference_scanner_beacon_world_positions = beacon_world_positions
+                [reference_scanner_index]
+                .as_ref()
+                .unwrap();
+
+            // Filter for possible observations of the same beacon from each scanner, based on the
+            // relative distances between the beacons each has seen.
+            let mut matches = Vec::new();
+            for reference_beacon_index in 0..scanners[reference_scanner_index].len() {
+                for unaligned_beacon_index in 0..scanners[unaligned_scanner_index].len() {
+                    let reference_scanner_distances = spacings
+                        .get(&(reference_scanner_index, reference_beacon_index))
+                        .unwrap();
+                    let unaligned_scanner_distances = spacings
+                        .get(&(unaligned_scanner_index, unaligned_beacon_index))
+                        .unwrap();
+                    // If they share 11 matching offsets to *other* beacons, then they're probably
+                    // part of an overlapping set of 12 or more beacons (including themselves).
+                    if reference_scanner_distances
+                        .intersection(unaligned_scanner_distances)
+                        .count()
+                        >= 11
+                    {
+                        matches.push((reference_beacon_index, unaligned_beacon_index));
+                    }
+                }
+            }
+
+            if matches.len() < 6 {
+                // We *should* have 12 overlapping, but if we don't have enough, this probably
+                // isn't a good match.
+                continue;
+            }
+
+            // Now, try all possible alignments (rotations) of the coordinates as seen from the unaligned
+            // scanner. We compute the offsets between each matched beacons: if we have found the correct
+            // alignment, all beacons should be offset from each other by the same amount (that amount
+            // being the offset between the two stations).
+            'next_rotation: for rotation in &all_rotations {
+                let mut offset = None;
+                for &(reference_beacon_index, unaligned_beacon_index) in &matches {
+                    let reference_beacon_world_position =
+                        reference_scanner_beacon_world_positions[reference_beacon_index];
+                    let unaligned_beacon_position =
+                        scanners[unaligned_scanner_index][unaligned_beacon_index];
+
+                    let maybe_aligned_position = rotation * unaligned_beacon_position.as_vector3();
+                    let maybe_offset =
+                        reference_beacon_world_position.as_vector3() - maybe_aligned_position;
+
+                    if offset.is_some() && offset.unwrap() != maybe_offset {
+                        // Have at least two supposed 'offsets', this is not the right rotation.
+                        continue 'next_rotation;
+                    }
+                    offset = Some(maybe_offset);
+                }
+
+                // We only found one offset--these sensors are aligned!
+                // We can populate the map and then go back to the top of the outer loop.
+                scanner_world_positions[unaligned_scanner_index] = offset;
+                let offset = offset.unwrap();
+                beacon_world_positions[unaligned_scanner_index] = Some(
+                    scanners[unaligned_scanner_index]
+                        .iter()
+                        .map(|relative_beacon_coords| {
+                            rotation * relative_beacon_coords.as_vector3() + offset
+                        })
+                        .map(Coordinate::from_vector3)
+                        .collect_vec(),
+                );
+                continue 'outer;
+            }
+        }
+
+        // We failed to align this sensor; re-queue it for later.
+        unaligned_scanners.push_back(unaligned_scanner_index);
+    }
+
+    (
+        scanner_world_positions
+            .into_iter()
+            .map(|s| s.unwrap())
+            .collect(),
+        beacon_world_positions
+            .into_iter()
+            .map(|b| b.unwrap())
+            .collect(),
+    )
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<Vec<Coordinate>>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Coordinate>>> {
+        let text = Text::new(Self::DAY, input);
+        let mut scanners: Vec<Vec<Coordinate>> = Vec::new();
+        for scanner_list in text.sections() {
+            let mut measurements = scanner_list.split("\n");
+            // discard header
+            match measurements.next() {
+                Some(header) if header.starts_with("--- scanner ") => (),
+                _ => {
+                    return Err(text
+                        .error(scanner_list, "expected a `--- scanner N ---` header")
+                        .into())
+                }
+            }
+
+            scanners.push(
+                measurements
+                    .map(|l| text.record::<Coordinate>(l))
+                    .collect::<Result<_, _>>()?,
+            );
+        }
+        Ok(scanners)
+    }
+
+    fn part_one(scanners: &Vec<Vec<Coordinate>>, _: &()) -> Result<usize> {
+        let (_, beacon_world_positions) = align(scanners);
+        let all_beacons: HashSet<Coordinate> =
+            beacon_world_positions.into_iter().flatten().collect();
+        Ok(all_beacons.len())
+    }
+
+    fn part_two(scanners: &Vec<Vec<Coordinate>>, _: &()) -> Result<i32> {
+        let (scanner_world_positions, _) = align(scanners);
+        scanner_world_positions
+            .into_iter()
+            .map(Coordinate::from_vector3)
+            .combinations(2)
+            .map(|pair| (pair[0] - pair[1]).dist())
+            .max()
+            .ok_or_else(|| eyre!("need at least two scanners"))
+    }
+}
+
+pub const EXAMPLE: &str = "--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14";
@@ -0,0 +1,120 @@
+use color_eyre::eyre::{bail, Result};
+
+use crate::grid::{window_key, SparseGrid};
+use crate::parse::Text;
+use crate::solution::{self, Override};
+use crate::Solution;
+
+fn enhance(image: &SparseGrid<char>, algorithm: &[char]) -> SparseGrid<char> {
+    let lit = |pixel: char| pixel == '#';
+    let background = window_key(&[[lit(image.background); 3]; 3]);
+    let mut enhanced = SparseGrid::new(algorithm[background]);
+    let Some(bounds) = image.bounds() else {
+        return enhanced;
+    };
+    // Pixels just beyond the image see some of it, so they can change too.
+    for coords in bounds.grow(1).cells() {
+        let window = image.window::<3>(coords).map(|row| row.map(lit));
+        enhanced.set(coords, algorithm[window_key(&window)]);
+    }
+    enhanced
+}
+
+fn num_lit(image: &SparseGrid<char>) -> usize {
+    image.iter().filter(|&(_, &pixel)| pixel == '#').count()
+}
+
+#[derive(Debug)]
+pub struct Scan {
+    algorithm: Vec<char>,
+    image: SparseGrid<char>,
+}
+
+fn enhance_times(scan: &Scan, times: usize) -> Result<usize> {
+    let mut image = scan.image.clone();
+    for _ in 0..times {
+        image = enhance(&image, &scan.algorithm);
+    }
+    // An algorithm that lights dark pixels with no lit neighbors lights the whole infinite
+    // background on every other pass.
+    if image.background == '#' {
+        bail!(
+            "infinitely many pixels are lit after {} passes; try an even number",
+            times
+        );
+    }
+    Ok(num_lit(&image))
+}
+
+/// How many times the image goes through the enhancement algorithm.
+#[derive(Debug, Default)]
+pub struct Options {
+    /// Twice for part one and 50 times for part two. An odd count can leave infinitely many lit.
+    passes: Override<usize>,
+}
+
+impl solution::Options for Options {
+    const NAMES: &'static [(&'static str, &'static str)] = &[(
+        "passes",
+        "how many times to enhance the image (2 for part one, 50 for part two)",
+    )];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        self.passes.set(name, value)
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Scan;
+    type Options = Options;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Scan> {
+        let text = Text::new(Self::DAY, input);
+        let pixel = |s: &str| match s.find(|c| c != '#' && c != '.') {
+            Some(i) => Err(text.error(&s[i..], "expected `#` or `.`")),
+            None => Ok(()),
+        };
+
+        let (algorithm, input_image) = text.two_sections(input, "the image")?;
+        pixel(algorithm)?;
+        if algorithm.len() != 512 {
+            return Err(text
+                .error(algorithm, "expected 512 pixels in the algorithm")
+                .into());
+        }
+        let algorithm: Vec<char> = algorithm.chars().collect();
+
+        let mut image = SparseGrid::new('.');
+        for (y, line) in input_image.split("\n").enumerate() {
+            pixel(line)?;
+            for (x, pixel) in line.chars().enumerate() {
+                image.set((x as i32, y as i32), pixel);
+            }
+        }
+
+        Ok(Scan { algorithm, image })
+    }
+
+    fn part_one(scan: &Scan, options: &Options) -> Result<usize> {
+        enhance_times(scan, options.passes.or(2))
+    }
+
+    fn part_two(scan: &Scan, options: &Options) -> Result<usize> {
+        enhance_times(scan, options.passes.or(50))
+    }
+}
+
+pub const EXAMPLE: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
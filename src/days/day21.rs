@@ -0,0 +1,175 @@
+use color_eyre::eyre::Result;
+use reformation::Reformation;
+
+use crate::parse::Text;
+use crate::solution::{self, option_value};
+use crate::Solution;
+
+#[derive(Debug, Reformation)]
+#[reformation("Player {player} starting position: {position}")]
+struct StartingPosition {
+    player: usize,
+    position: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Player {
+    position: usize,
+    score: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Game {
+    players: [Player; 2],
+    current_player: usize,
+    rounds_played: usize,
+    win_at: usize,
+}
+
+impl Game {
+    fn new(initial_positions: [usize; 2], win_at: usize) -> Game {
+        Game {
+            players: [
+                Player {
+                    position: initial_positions[0],
+                    score: 0,
+                },
+                Player {
+                    position: initial_positions[1],
+                    score: 0,
+                },
+            ],
+            current_player: 0,
+            rounds_played: 0,
+            win_at,
+        }
+    }
+
+    fn do_turn<R>(&mut self, rolls: R) -> bool
+    where
+        R: Fn(usize) -> usize,
+    {
+        let round = self.rounds_played;
+        let rolls = rolls(round);
+        self.rounds_played += 1;
+
+        let player = &mut self.players[self.current_player];
+        player.position = ((player.position - 1) + rolls) % 10 + 1;
+        player.score += player.position;
+        if player.score >= self.win_at {
+            return true;
+        }
+
+        self.current_player ^= 1;
+        false
+    }
+
+    fn part_one(&mut self) -> usize {
+        let deterministic_dice = |round| {
+            let mut rolls = (3 * round) % 100 + 1;
+            rolls += (3 * round + 1) % 100 + 1;
+            rolls += (3 * round + 2) % 100 + 1;
+            rolls
+        };
+        while !self.do_turn(deterministic_dice) {}
+
+        let loser = self.current_player ^ 1;
+        let loser = &self.players[loser];
+        loser.score * self.rounds_played * 3
+    }
+
+    fn part_two(&mut self) -> [usize; 2] {
+        let mut wins = [0; 2];
+
+        // (rolled value, frequency)
+        const ROLLS: [(usize, usize); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+        for (roll_value, roll_frequency) in &ROLLS {
+            let mut copy = *self;
+            let roll = |_| *roll_value;
+            if copy.do_turn(roll) {
+                wins[copy.current_player] += roll_frequency;
+            } else {
+                for (player, new_wins) in copy.part_two().iter().enumerate() {
+                    wins[player] += new_wins * roll_frequency;
+                }
+            }
+        }
+
+        wins
+    }
+}
+
+/// What it takes to win the practice game in part one, 1000 points by default. Part two always
+/// plays to 21: the number of universes grows far too quickly to count them for much more.
+#[derive(Debug)]
+pub struct Options {
+    win_at: usize,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options { win_at: 1000 }
+    }
+}
+
+impl solution::Options for Options {
+    const NAMES: &'static [(&'static str, &'static str)] = &[(
+        "win_at",
+        "the score that wins part one's game with the practice die (1000)",
+    )];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        self.win_at = option_value(name, value)?;
+        Ok(())
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = [usize; 2];
+    type Options = Options;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<[usize; 2]> {
+        let text = Text::new(Self::DAY, input);
+        let mut positions = [0; 2];
+        for line in text.lines() {
+            let start: StartingPosition = text.record(line)?;
+            let position = positions
+                .get_mut(start.player.wrapping_sub(1))
+                .ok_or_else(|| text.error(line, "only players 1 and 2 are playing"))?;
+            if !(1..=10).contains(&start.position) {
+                return Err(text.error(line, "the track only has spaces 1 to 10").into());
+            }
+            *position = start.position;
+        }
+        if let Some(player) = positions.iter().position(|&p| p == 0) {
+            return Err(text
+                .error(
+                    text.end(),
+                    format!("player {} has no starting position", player + 1),
+                )
+                .into());
+        }
+        Ok(positions)
+    }
+
+    fn part_one(positions: &[usize; 2], options: &Options) -> Result<usize> {
+        let mut game = Game::new(*positions, options.win_at);
+        Ok(game.part_one())
+    }
+
+    fn part_two(positions: &[usize; 2], _: &Options) -> Result<usize> {
+        let mut game = Game::new(*positions, 21);
+        Ok(game.part_two().into_iter().max().unwrap())
+    }
+}
+
+pub const EXAMPLE: &str = "Player 1 starting position: 4
+Player 2 starting position: 8";
@@ -0,0 +1,556 @@
+use color_eyre::eyre::{bail, Result};
+use reformation::Reformation;
+
+use crate::parse::Text;
+use crate::solution::{self, option_value};
+use crate::Solution;
+
+#[derive(Debug, Reformation)]
+#[reformation("{state} {cube}")]
+pub struct Instruction {
+    state: String,
+    cube: Cube,
+}
+
+#[derive(Debug, Reformation, Clone, Copy)]
+#[reformation("x={x},y={y},z={z}")]
+struct Cube {
+    x: Interval,
+    y: Interval,
+    z: Interval,
+}
+
+impl Cube {
+    fn contains(&self, other: &Cube) -> bool {
+        self.x.contains(&other.x) && self.y.contains(&other.y) && self.z.contains(&other.z)
+    }
+
+    fn axis(&self, n: u8) -> &Interval {
+        match n {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("bad axis {}", n),
+        }
+    }
+
+    fn axis_mut(&mut self, n: u8) -> &mut Interval {
+        match n {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("bad axis {}", n),
+        }
+    }
+
+    fn split(&self, axis: u8, left_max: i32) -> (Option<Cube>, Option<Cube>) {
+        if self.axis(axis).min > left_max {
+            // We fall entirely to the 'right' of the splitting plane.
+            (None, Some(*self))
+        } else if self.axis(axis).max <= left_max {
+            // We fall entirely to the 'left' of the splitting plane.
+            (Some(*self), None)
+        } else {
+            // We span the plane; split into two pieces.
+            let mut left = *self;
+            left.axis_mut(axis).max = left_max;
+            let mut right = *self;
+            right.axis_mut(axis).min = left_max + 1;
+
+            (Some(left), Some(right))
+        }
+    }
+
+    fn intersects(&self, other: &Cube) -> bool {
+        self.x.intersects(&other.x) && self.y.intersects(&other.y) && self.z.intersects(&other.z)
+    }
+
+    /// The part of this cube that's also in `other`, if any.
+    fn intersection(&self, other: &Cube) -> Option<Cube> {
+        Some(Cube {
+            x: self.x.intersection(&other.x)?,
+            y: self.y.intersection(&other.y)?,
+            z: self.z.intersection(&other.z)?,
+        })
+    }
+
+    fn volume(&self) -> usize {
+        let x_len = self.x.len();
+        let y_len = self.y.len();
+        let z_len = self.z.len();
+        x_len * y_len * z_len
+    }
+}
+
+#[derive(Debug, Reformation, Clone, Copy)]
+#[reformation("{min}..{max}")]
+struct Interval {
+    min: i32,
+    max: i32,
+}
+
+impl Interval {
+    fn contains(&self, other: &Interval) -> bool {
+        self.min <= other.min && self.max >= other.max
+    }
+
+    fn intersects(&self, other: &Interval) -> bool {
+        !(self.max < other.min || self.min > other.max)
+    }
+
+    fn intersection(&self, other: &Interval) -> Option<Interval> {
+        match self.intersects(other) {
+            true => Some(Interval {
+                min: self.min.max(other.min),
+                max: self.max.min(other.max),
+            }),
+            false => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        assert!(self.max >= self.min);
+        (self.max - self.min + 1) as usize
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Leaf(Cube),
+    Branch {
+        axis: u8,
+        left_max: i32,
+        left: Box<Node>,
+        right: Box<Node>,
+    },
+}
+
+impl Node {
+    fn add(&mut self, cube: &Cube) {
+        match self {
+            Node::Leaf(us) => {
+                if us.contains(cube) {
+                    // These lights were all already on.
+                    return;
+                }
+
+                if cube.contains(us) {
+                    // These lights are a superset of us.
+                    *self = Node::Leaf(*cube);
+                    return;
+                }
+
+                // These cubes either don't overlap, or partially overlap.
+                // Let's hope it's the former.
+                for axis in 0..3 {
+                    if us.axis(axis).max < cube.axis(axis).min {
+                        // We're fully to the 'left' on this axis.
+                        *self = Node::Branch {
+                            axis,
+                            left_max: us.axis(axis).max,
+                            left: Box::new(Node::Leaf(*us)),
+                            right: Box::new(Node::Leaf(*cube)),
+                        };
+                        return;
+                    }
+                    if cube.axis(axis).max < us.axis(axis).min {
+                        // The new cube is fully to the 'left' on this axis.
+                        *self = Node::Branch {
+                            axis,
+                            left_max: cube.axis(axis).max,
+                            left: Box::new(Node::Leaf(*cube)),
+                            right: Box::new(Node::Leaf(*us)),
+                        };
+                        return;
+                    }
+                }
+
+                // There's at least a little bit of overlap on every axis.
+                // The goal now is to pare off chunks of `cube` that 'stick out' from the side of
+                // our existing cuboid. As we do, we recursively keep adding what's left over;
+                // we should eventually be left with a piece that's fully contained.
+                for axis in 0..3 {
+                    // Partial overlap can happen in two ways, either on the left...
+                    // |-----cube--->
+                    //      |---us-->
+                    if cube.axis(axis).min < us.axis(axis).min {
+                        let left_max = us.axis(axis).min - 1;
+
+                        let mut left = *cube;
+                        left.axis_mut(axis).max = left_max;
+                        let left = Box::new(Node::Leaf(left));
+
+                        let mut remaining = *cube;
+                        remaining.axis_mut(axis).min = us.axis(axis).min;
+                        let mut right = Box::new(Node::Leaf(*us));
+                        right.add(&remaining);
+
+                        *self = Node::Branch {
+                            axis,
+                            left_max,
+                            left,
+                            right,
+                        };
+                        return;
+                    }
+
+                    // ...or on the right:
+                    // <-----cube-----|
+                    // <---us---|
+                    if cube.axis(axis).max > us.axis(axis).max {
+                        let left_max = us.axis(axis).max;
+
+                        let mut right = *cube;
+                        right.axis_mut(axis).min = us.axis(axis).max + 1;
+                        let right = Box::new(Node::Leaf(right));
+
+                        let mut remaining = *cube;
+                        remaining.axis_mut(axis).max = us.axis(axis).max;
+                        let mut left = Box::new(Node::Leaf(*us));
+                        left.add(&remaining);
+
+                        *self = Node::Branch {
+                            axis,
+                            left_max,
+                            left,
+                            right,
+                        };
+                        return;
+                    }
+                }
+
+                unreachable!();
+            }
+            Node::Branch {
+                axis,
+                left_max,
+                left,
+                right,
+            } => {
+                let (cube_left, cube_right) = cube.split(*axis, *left_max);
+                if let Some(cube_left) = cube_left {
+                    left.add(&cube_left);
+                }
+                if let Some(cube_right) = cube_right {
+                    right.add(&cube_right);
+                }
+            }
+        }
+    }
+
+    fn remove(self, cube: &Cube) -> Option<Node> {
+        match self {
+            Node::Leaf(us) => {
+                if !cube.intersects(&us) {
+                    return Some(self);
+                }
+
+                if cube.contains(&us) {
+                    // This turns off all the cubes down this branch.
+                    return None;
+                }
+
+                // We need to pare off bits of the cube which are *not* going to be turned off.
+                // Then we keep recursively removing against the remaining volume, and eventually
+                // `cube` should fully contain whatever is left.
+                for axis in 0..3 {
+                    // Partial overlap can happen in two ways, either on the left...
+                    // |-------us------>
+                    //      |---cube--->
+                    if us.axis(axis).min < cube.axis(axis).min {
+                        let left_max = cube.axis(axis).min - 1;
+
+                        let mut left = us;
+                        left.axis_mut(axis).max = left_max;
+                        let left = Node::Leaf(left);
+
+                        let mut right = us;
+                        right.axis_mut(axis).min = cube.axis(axis).min;
+                        let right = Node::Leaf(right);
+                        if let Some(right) = right.remove(cube) {
+                            return Some(Node::Branch {
+                                axis,
+                                left_max,
+                                left: Box::new(left),
+                                right: Box::new(right),
+                            });
+                        } else {
+                            // Everything to the right got turned off, so we don't need a branch.
+                            return Some(left);
+                        }
+                    }
+
+                    // ...or on the right:
+                    // <-----us-------|
+                    // <---cube---|
+                    if us.axis(axis).max > cube.axis(axis).max {
+                        let left_max = cube.axis(axis).max;
+
+                        let mut right = us;
+                        right.axis_mut(axis).min = left_max + 1;
+                        let right = Node::Leaf(right);
+
+                        let mut left = us;
+                        left.axis_mut(axis).max = left_max;
+                        let left = Node::Leaf(left);
+                        if let Some(left) = left.remove(cube) {
+                            return Some(Node::Branch {
+                                axis,
+                                left_max,
+                                left: Box::new(left),
+                                right: Box::new(right),
+                            });
+                        } else {
+                            // Everything to the left got turned off, so we don't need a branch.
+                            return Some(right);
+                        }
+                    }
+                }
+
+                unreachable!();
+            }
+            Node::Branch {
+                axis,
+                left_max,
+                left,
+                right,
+            } => {
+                let mut left = Some(*left);
+                let mut right = Some(*right);
+
+                let (cube_left, cube_right) = cube.split(axis, left_max);
+                if let Some(cube_left) = cube_left {
+                    left = left.unwrap().remove(&cube_left);
+                }
+                if let Some(cube_right) = cube_right {
+                    right = right.unwrap().remove(&cube_right);
+                }
+
+                match (left, right) {
+                    (None, None) => None,
+                    (Some(left), None) => Some(left),
+                    (None, Some(right)) => Some(right),
+                    (Some(left), Some(right)) => Some(Node::Branch {
+                        axis,
+                        left_max,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    }),
+                }
+            }
+        }
+    }
+
+    fn volume(&self) -> usize {
+        match self {
+            Node::Leaf(cube) => cube.volume(),
+            Node::Branch { left, right, .. } => left.volume() + right.volume(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Reactor {
+    on_cubes: Option<Node>,
+}
+
+impl Reactor {
+    fn turn_on(&mut self, cube: &Cube) {
+        match self.on_cubes.as_mut() {
+            None => self.on_cubes = Some(Node::Leaf(*cube)),
+            Some(tree) => tree.add(cube),
+        }
+    }
+
+    fn turn_off(&mut self, cube: &Cube) {
+        match self.on_cubes.take() {
+            None => (),
+            Some(tree) => self.on_cubes = tree.remove(cube),
+        }
+    }
+
+    fn volume(&self) -> usize {
+        self.on_cubes.as_ref().map_or(0, |node| node.volume())
+    }
+}
+
+/// How much of the reactor part one reboots: by default, the cubes within 50 of the origin on
+/// every axis.
+#[derive(Debug)]
+pub struct Options {
+    region: i32,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options { region: 50 }
+    }
+}
+
+impl solution::Options for Options {
+    const NAMES: &'static [(&'static str, &'static str)] = &[(
+        "region",
+        "how far part one's initialization region reaches from the origin on each axis (50)",
+    )];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        self.region = option_value(name, value)?;
+        if self.region < 0 {
+            bail!("the region can't reach a negative distance");
+        }
+        Ok(())
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Vec<Instruction>;
+    type Options = Options;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        let text = Text::new(Self::DAY, input);
+        Ok(text
+            .lines()
+            .map(|line| match text.record::<Instruction>(line)? {
+                instruction if instruction.state == "on" || instruction.state == "off" => {
+                    Ok(instruction)
+                }
+                _ => Err(text.error(line, "expected `on` or `off`")),
+            })
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn part_one(instructions: &Vec<Instruction>, options: &Options) -> Result<usize> {
+        let reach = Interval {
+            min: -options.region,
+            max: options.region,
+        };
+        let region = Cube {
+            x: reach,
+            y: reach,
+            z: reach,
+        };
+        let mut reactor = Reactor::default();
+        for instruction in instructions {
+            // Only the part of each cube inside the region counts.
+            let cube = match instruction.cube.intersection(&region) {
+                Some(cube) => cube,
+                None => continue,
+            };
+
+            if instruction.state == "on" {
+                reactor.turn_on(&cube);
+            } else {
+                reactor.turn_off(&cube);
+            }
+        }
+        Ok(reactor.volume())
+    }
+
+    fn part_two(instructions: &Vec<Instruction>, _: &Options) -> Result<usize> {
+        let mut reactor = Reactor::default();
+        for instruction in instructions {
+            if instruction.state == "on" {
+                reactor.turn_on(&instruction.cube);
+            } else {
+                reactor.turn_off(&instruction.cube);
+            }
+        }
+        Ok(reactor.volume())
+    }
+}
+
+pub const MINI_EXAMPLE: &str = "on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10";
+
+pub const EXAMPLE: &str = "on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682";
+
+pub const MEGA_EXAMPLE: &str = "on x=-5..47,y=-31..22,z=-19..33
+on x=-44..5,y=-27..21,z=-14..35
+on x=-49..-1,y=-11..42,z=-10..38
+on x=-20..34,y=-40..6,z=-44..1
+off x=26..39,y=40..50,z=-2..11
+on x=-41..5,y=-41..6,z=-36..8
+off x=-43..-33,y=-45..-28,z=7..25
+on x=-33..15,y=-32..19,z=-34..11
+off x=35..47,y=-46..-34,z=-11..5
+on x=-14..36,y=-6..44,z=-16..29
+on x=-57795..-6158,y=29564..72030,z=20435..90618
+on x=36731..105352,y=-21140..28532,z=16094..90401
+on x=30999..107136,y=-53464..15513,z=8553..71215
+on x=13528..83982,y=-99403..-27377,z=-24141..23996
+on x=-72682..-12347,y=18159..111354,z=7391..80950
+on x=-1060..80757,y=-65301..-20884,z=-103788..-16709
+on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856
+on x=-52752..22273,y=-49450..9096,z=54442..119054
+on x=-29982..40483,y=-108474..-28371,z=-24328..38471
+on x=-4958..62750,y=40422..118853,z=-7672..65583
+on x=55694..108686,y=-43367..46958,z=-26781..48729
+on x=-98497..-18186,y=-63569..3412,z=1232..88485
+on x=-726..56291,y=-62629..13224,z=18033..85226
+on x=-110886..-34664,y=-81338..-8658,z=8914..63723
+on x=-55829..24974,y=-16897..54165,z=-121762..-28058
+on x=-65152..-11147,y=22489..91432,z=-58782..1780
+on x=-120100..-32970,y=-46592..27473,z=-11695..61039
+on x=-18631..37533,y=-124565..-50804,z=-35667..28308
+on x=-57817..18248,y=49321..117703,z=5745..55881
+on x=14781..98692,y=-1341..70827,z=15753..70151
+on x=-34419..55919,y=-19626..40991,z=39015..114138
+on x=-60785..11593,y=-56135..2999,z=-95368..-26915
+on x=-32178..58085,y=17647..101866,z=-91405..-8878
+on x=-53655..12091,y=50097..105568,z=-75335..-4862
+on x=-111166..-40997,y=-71714..2688,z=5609..50954
+on x=-16602..70118,y=-98693..-44401,z=5197..76897
+on x=16383..101554,y=4615..83635,z=-44907..18747
+off x=-95822..-15171,y=-19987..48940,z=10804..104439
+on x=-89813..-14614,y=16069..88491,z=-3297..45228
+on x=41075..99376,y=-20427..49978,z=-52012..13762
+on x=-21330..50085,y=-17944..62733,z=-112280..-30197
+on x=-16478..35915,y=36008..118594,z=-7885..47086
+off x=-98156..-27851,y=-49952..43171,z=-99005..-8456
+off x=2032..69770,y=-71013..4824,z=7471..94418
+on x=43670..120875,y=-42068..12382,z=-24787..38892
+off x=37514..111226,y=-45862..25743,z=-16714..54663
+off x=25699..97951,y=-30668..59918,z=-15349..69697
+off x=-44271..17935,y=-9516..60759,z=49131..112598
+on x=-61695..-5813,y=40978..94975,z=8655..80240
+off x=-101086..-9439,y=-7088..67543,z=33935..83858
+off x=18020..114017,y=-48931..32606,z=21474..89843
+off x=-77139..10506,y=-89994..-18797,z=-80..59318
+off x=8476..79288,y=-75520..11602,z=-96624..-24783
+on x=-47488..-1262,y=24338..100707,z=16292..72967
+off x=-84341..13987,y=2429..92914,z=-90671..-1318
+off x=-37810..49457,y=-71013..-7894,z=-105357..-13188
+off x=-27365..46395,y=31009..98017,z=15428..76570
+off x=-70369..-16548,y=22648..78696,z=-1892..86821
+on x=-53470..21291,y=-120233..-33476,z=-44150..38147
+off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
@@ -1,8 +1,12 @@
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 use pathfinding::prelude::{dijkstra, dijkstra_all};
 use std::collections::HashMap;
 
+use crate::grid::Grid;
+use crate::parse::Text;
+use crate::Solution;
+
 // If the 7 hallway spaces not in front of doors can be in ~5 states (empty/a/b/c/d) and the rooms
 // can be in ~3 states (empty/correct/incorrect) then we have something like ~512M states?
 
@@ -23,7 +27,7 @@ use std::collections::HashMap;
 //  21+22 are also Desert rooms in part two
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Amphipod {
+pub enum Amphipod {
     Amber = 1,
     Bronze = 2,
     Copper = 3,
@@ -53,6 +57,15 @@ impl Amphipod {
         }
     }
 
+    fn letter(self) -> char {
+        match self {
+            Amphipod::Amber => 'A',
+            Amphipod::Bronze => 'B',
+            Amphipod::Copper => 'C',
+            Amphipod::Desert => 'D',
+        }
+    }
+
     fn allowed_at(self, position: usize) -> bool {
         (ALLOWED[position] & self.mask()) != 0
     }
@@ -71,7 +84,7 @@ struct Burrow {
 
 // For each place on the diagram, the list of places it's connected to and an associated
 // multiplier. (The multiplier captures that 'skipping' a door costs 2 moves.)
-const ADJACENCY: &'static [&'static [(usize, usize)]] = &[
+const ADJACENCY: &[&[(usize, usize)]] = &[
     &[(1, 1)],
     &[(0, 1), (2, 2), (7, 2)],
     &[(1, 2), (7, 2), (9, 2), (3, 2)],
@@ -98,6 +111,7 @@ const ADJACENCY: &'static [&'static [(usize, usize)]] = &[
     &[(21, 1)],
 ];
 
+#[allow(clippy::unusual_byte_groupings)] // bit 0 is unused, see `Amphipod::mask`
 const ALLOWED: [u8; 15 + 8] = [
     0b1111_0, // 0
     0b1111_0, // 1
@@ -206,7 +220,7 @@ impl Burrow {
                 for (target, (_, steps)) in possible_targets.iter() {
                     let to_hallway = *target <= 6;
                     let to_a_home = !to_hallway;
-                    let to_my_home = amphipod.home().contains(&target);
+                    let to_my_home = amphipod.home().contains(target);
 
                     let legal = (!to_hallway || !in_hallway)
                         && (!to_a_home || (to_my_home && !strangers_in_my_home));
@@ -226,129 +240,123 @@ impl Burrow {
 
 impl std::fmt::Display for Burrow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("#############\n#")?;
-        render(f, &self.diagram[0])?;
-        render(f, &self.diagram[1])?;
-        f.write_str(".")?;
-        render(f, &self.diagram[2])?;
-        f.write_str(".")?;
-        render(f, &self.diagram[3])?;
-        f.write_str(".")?;
-        render(f, &self.diagram[4])?;
-        f.write_str(".")?;
-        render(f, &self.diagram[5])?;
-        render(f, &self.diagram[6])?;
-        f.write_str("#\n###")?;
-        render(f, &self.diagram[7])?;
-        f.write_str("#")?;
-        render(f, &self.diagram[9])?;
-        f.write_str("#")?;
-        render(f, &self.diagram[11])?;
-        f.write_str("#")?;
-        render(f, &self.diagram[13])?;
-        f.write_str("###\n  #")?;
-        render(f, &self.diagram[8])?;
-        f.write_str("#")?;
-        render(f, &self.diagram[10])?;
-        f.write_str("#")?;
-        render(f, &self.diagram[12])?;
-        f.write_str("#")?;
-        render(f, &self.diagram[14])?;
-        if self.map_size > 15 {
-            f.write_str("#\n  #")?;
-            render(f, &self.diagram[15])?;
-            f.write_str("#")?;
-            render(f, &self.diagram[17])?;
-            f.write_str("#")?;
-            render(f, &self.diagram[19])?;
-            f.write_str("#")?;
-            render(f, &self.diagram[21])?;
-            f.write_str("#\n  #")?;
-            render(f, &self.diagram[16])?;
-            f.write_str("#")?;
-            render(f, &self.diagram[18])?;
-            f.write_str("#")?;
-            render(f, &self.diagram[20])?;
-            f.write_str("#")?;
-            render(f, &self.diagram[22])?;
+        let depth = if self.map_size > 15 { 4 } else { 2 };
+        let height = depth + 3;
+        // Cells outside the burrow are left as the default `'\0'`, and drawn as spaces.
+        let mut grid: Grid<char> = Grid::new(0, 13, 0, height);
+        for (x, y) in grid.cells() {
+            let doors = [3, 5, 7, 9].contains(&x);
+            let under_hallway = (2..=10).contains(&x);
+            grid[(x, y)] = match y {
+                0 => '#',
+                1 if x == 0 || x == 12 => '#',
+                1 => '.',
+                _ if y == height - 1 && under_hallway => '#',
+                _ if y < height - 1 && doors => '.',
+                _ if y == 2 || (y < height - 1 && under_hallway) => '#',
+                _ => '\0',
+            };
+        }
+
+        // The hallway spaces not in front of a door, then each room from the top down.
+        let hallway = [1, 2, 4, 6, 8, 10, 11];
+        for (place, x) in hallway.into_iter().enumerate() {
+            grid[(x, 1)] = self.diagram[place].map_or('.', Amphipod::letter);
         }
-        f.write_str("#\n  #########\n")
+        for room in 0..4 {
+            for y in 0..depth as usize {
+                let place = match y {
+                    0 | 1 => 7 + 2 * room + y,
+                    _ => 15 + 2 * room + y - 2,
+                };
+                grid[(3 + 2 * room as i32, 2 + y as i32)] =
+                    self.diagram[place].map_or('.', Amphipod::letter);
+            }
+        }
+
+        let drawn = grid
+            .render(|&c| if c == '\0' { ' ' } else { c })
+            .to_string();
+        for line in drawn.split("\n") {
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
     }
 }
 
 impl std::fmt::Display for Amphipod {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Amphipod::Amber => f.write_str("A"),
-            Amphipod::Bronze => f.write_str("B"),
-            Amphipod::Copper => f.write_str("C"),
-            Amphipod::Desert => f.write_str("D"),
-        }
+        write!(f, "{}", self.letter())
     }
 }
 
-fn render(f: &mut std::fmt::Formatter<'_>, maybe_amphipod: &Option<Amphipod>) -> std::fmt::Result {
-    match maybe_amphipod {
-        None => f.write_str("."),
-        Some(amphipod) => write!(f, "{}", amphipod),
-    }
+fn least_energy(start: &Burrow, goal: &Burrow) -> Result<usize> {
+    let (_, energy) = dijkstra(start, |burrow| burrow.reachable(), |burrow| burrow == goal)
+        .ok_or_else(|| eyre!("the amphipods can't be organized"))?;
+    Ok(energy)
 }
 
-fn main() -> Result<()> {
-    color_eyre::install()?;
-
-    // Part One
-
-    // Example
-    //let input = Burrow::for_part_one([
-    //    B, C, B, D, // top row
-    //    A, D, C, A, // bottom row
-    //]);
-    // My input
-    let input = Burrow::for_part_one([
-        B, B, C, D, // top row
-        D, A, A, C, // bottom row
-    ]);
-
-    let goal = Burrow::for_part_one([
-        A, B, C, D, // top row
-        A, B, C, D, // bottom row
-    ]);
-
-    let (_, part_one_answer) = dijkstra(
-        &input,
-        |burrow| burrow.reachable(),
-        |&burrow| burrow == goal,
-    )
-    .unwrap();
-    dbg!(part_one_answer);
-
-    // Part Two
-
-    // Example
-    //let input = Burrow::for_part_two([
-    //    B, C, B, D,
-    //    D, C, B, A,
-    //    D, B, A, C,
-    //    A, D, C, A,
-    //]);
-    // My input
-    let input = Burrow::for_part_two([
-        B, B, C, D, // top row
-        D, C, B, A, // from instructions
-        D, B, A, C, // from instructions
-        D, A, A, C, // bottom row
-    ]);
-
-    let goal = Burrow::for_part_two([A, B, C, D, A, B, C, D, A, B, C, D, A, B, C, D]);
-
-    let (_, part_two_answer) = dijkstra(
-        &input,
-        |burrow| burrow.reachable(),
-        |&burrow| burrow == goal,
-    )
-    .unwrap();
-    dbg!(part_two_answer);
-
-    Ok(())
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = [Amphipod; 8];
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<[Amphipod; 8]> {
+        let text = Text::new(Self::DAY, input);
+        if let Some(i) = input.find(|c| !"#.ABCD \n".contains(c)) {
+            return Err(text
+                .error(&input[i..], "expected a wall, space or amphipod")
+                .into());
+        }
+        let amphipods: Vec<_> = input
+            .chars()
+            .filter_map(|c| match c {
+                'A' => Some(A),
+                'B' => Some(B),
+                'C' => Some(C),
+                'D' => Some(D),
+                _ => None,
+            })
+            .collect();
+        let found = amphipods.len();
+        Ok(amphipods.try_into().map_err(|_| {
+            text.error(text.end(), format!("expected 8 amphipods, found {}", found))
+        })?)
+    }
+
+    fn part_one(setup: &[Amphipod; 8], _: &()) -> Result<usize> {
+        let start = Burrow::for_part_one(*setup);
+
+        let goal = Burrow::for_part_one([
+            A, B, C, D, // top row
+            A, B, C, D, // bottom row
+        ]);
+
+        least_energy(&start, &goal)
+    }
+
+    fn part_two(setup: &[Amphipod; 8], _: &()) -> Result<usize> {
+        let start = Burrow::for_part_two([
+            setup[0], setup[1], setup[2], setup[3], // top row
+            D, C, B, A, // from instructions
+            D, B, A, C, // from instructions
+            setup[4], setup[5], setup[6], setup[7], // bottom row
+        ]);
+
+        let goal = Burrow::for_part_two([A, B, C, D, A, B, C, D, A, B, C, D, A, B, C, D]);
+
+        least_energy(&start, &goal)
+    }
 }
+
+pub const EXAMPLE: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
@@ -3,8 +3,11 @@ use reformation::Reformation;
 
 use rustc_hash::FxHashMap;
 
+use crate::parse::Text;
+use crate::Solution;
+
 #[derive(Debug, Reformation, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum Instruction {
+pub enum Instruction {
     #[reformation("inp {}")]
     Input(Operand),
     #[reformation("add {} {}")]
@@ -20,7 +23,7 @@ enum Instruction {
 }
 
 #[derive(Debug, Reformation, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum Operand {
+pub enum Operand {
     #[reformation("w")]
     W,
     #[reformation("x")]
@@ -33,6 +36,7 @@ enum Operand {
     Literal(i64),
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct ALU {
     w: i64,
@@ -63,7 +67,7 @@ impl ALU {
     }
 
     fn run(&self, instruction: Instruction, input: Option<i64>) -> Result<ALU> {
-        let mut alu = self.clone();
+        let mut alu = *self;
 
         match instruction {
             Instruction::Input(a) => {
@@ -101,7 +105,7 @@ fn handle_digit(input: i64, mut z: i64, divisor: i64, addend1: i64, addend2: i64
     let x = z % 26;
 
     // div z {}
-    z = z / divisor;
+    z /= divisor;
 
     let x = x + addend1; // add x {}
 
@@ -171,33 +175,129 @@ fn validator(_input: [i64; 14]) -> bool {
     // input[13] = input[0] - 7
 }
 
-fn part_one() -> i64 {
-    99_196_997_985_942
+/// The constants that differ between the program's per-digit blocks, see `handle_digit`.
+#[derive(Debug, Clone, Copy)]
+struct Block {
+    divisor: i64,
+    addend1: i64,
+    addend2: i64,
+}
+
+const BLOCK_LEN: usize = 18;
+
+fn blocks(program: &[Instruction]) -> Result<Vec<Block>> {
+    use Instruction::*;
+    use Operand::*;
+
+    if program.len() != 14 * BLOCK_LEN {
+        bail!("expected 14 blocks of {} instructions", BLOCK_LEN);
+    }
+    program
+        .chunks(BLOCK_LEN)
+        .enumerate()
+        .map(|(i, block)| match (block[4], block[5], block[15]) {
+            (Div(Z, Literal(divisor)), Add(X, Literal(addend1)), Add(Y, Literal(addend2))) => {
+                Ok(Block {
+                    divisor,
+                    addend1,
+                    addend2,
+                })
+            }
+            _ => Err(eyre!("block {} doesn't look like the others", i)),
+        })
+        .collect()
 }
 
-fn part_two() -> i64 {
-    84_191_521_311_611
+// Being "smart"
+// (aka having a lot of time to reconsider my life decisions while brute force finishes)
+// While decompiling things into source I realized it's checking one digit at a time, against
+// some previous digit, using the 'z' register as a sort of stack.
+// From that we can work out the constraints on each digit, and from *that* we can just
+// compute the max and min directly.
+fn model_number(program: &[Instruction], largest: bool) -> Result<i64> {
+    let mut digits = [0; 14];
+    let mut stack = Vec::new();
+    for (i, block) in blocks(program)?.into_iter().enumerate() {
+        if block.divisor == 1 {
+            // push input[i] + addend2
+            stack.push((i, block.addend2));
+            continue;
+        }
+
+        // input[i] = (pop) + addend1
+        let (j, addend2) = stack
+            .pop()
+            .ok_or_else(|| eyre!("block {} pops an empty stack", i))?;
+        let offset = addend2 + block.addend1;
+        let (digit_j, digit_i) = if largest {
+            (9.min(9 - offset), 9.min(9 + offset))
+        } else {
+            (1.max(1 - offset), 1.max(1 + offset))
+        };
+        if !(1..=9).contains(&digit_j) || !(1..=9).contains(&digit_i) {
+            bail!("digits {} and {} can't differ by {}", j, i, offset);
+        }
+        digits[j] = digit_j;
+        digits[i] = digit_i;
+    }
+    if !stack.is_empty() {
+        bail!("z isn't empty at the end of the program");
+    }
+
+    Ok(digits.iter().fold(0, |number, digit| 10 * number + digit))
 }
 
-fn main() -> Result<()> {
-    color_eyre::install()?;
+pub struct Day24;
 
-    // Being "smart"
-    // (aka having a lot of time to reconsider my life decisions while brute force finishes)
-    // While decompiling things into source I realized it's checking one digit at a time, against
-    // some previous digit, using the 'z' register as a sort of stack.
-    // From that we can hand-compute the constraints on each digit, and from *that* we can just
-    // hand compute the max and min directly.
-    dbg!(part_one());
-    dbg!(part_two());
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const EXAMPLE: Option<&'static str> = None;
 
-    // Brute force
-    // I left this because it *does* seem to work. It's rather slow and will munch a bunch of RAM.
+    type Input = Vec<Instruction>;
+    type Options = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        use Instruction::*;
+
+        let text = Text::new(Self::DAY, input);
+        Ok(text
+            .lines()
+            .map(|line| {
+                let instruction: Instruction = text.record(line)?;
+                match instruction {
+                    Input(Operand::Literal(_))
+                    | Add(Operand::Literal(_), _)
+                    | Mul(Operand::Literal(_), _)
+                    | Div(Operand::Literal(_), _)
+                    | Mod(Operand::Literal(_), _)
+                    | Eql(Operand::Literal(_), _) => {
+                        Err(text.error(line, "can't store into a literal"))
+                    }
+                    _ => Ok(instruction),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn part_one(program: &Vec<Instruction>, _: &()) -> Result<i64> {
+        model_number(program, true)
+    }
+
+    fn part_two(program: &Vec<Instruction>, _: &()) -> Result<i64> {
+        model_number(program, false)
+    }
+}
+
+/// Finds the largest accepted model number by running every possible input.
+///
+/// I left this because it *does* seem to work. It's rather slow and will munch a bunch of RAM.
+pub fn brute_force(program: &[Instruction]) -> Result<i64> {
     let mut states: FxHashMap<ALU, i64> = FxHashMap::default();
     states.insert(ALU::default(), 0);
 
-    for (i, line) in INPUT.split("\n").enumerate() {
-        let instruction = Instruction::parse(line)?;
+    for (i, &instruction) in program.iter().enumerate() {
         match instruction {
             Instruction::Input(_) => {
                 // Split a new state off for every possible input digit.
@@ -242,261 +342,9 @@ fn main() -> Result<()> {
     states.retain(|alu, _| alu.z == 0);
     eprintln!("now just {} states", states.len());
 
-    dbg!(states.values().max());
-    dbg!(states.values().min());
-
-    Ok(())
+    states
+        .values()
+        .max()
+        .copied()
+        .ok_or_else(|| eyre!("no model numbers were accepted"))
 }
-
-const INPUT: &'static str = "inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 12
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 1
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 12
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 1
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 15
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 16
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -8
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 5
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -4
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 9
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 15
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 3
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 14
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 2
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 14
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 15
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -13
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 5
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -3
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 11
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -7
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 7
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 10
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 1
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -6
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 10
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -8
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 3
-mul y x
-add z y";
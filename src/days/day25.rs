@@ -0,0 +1,92 @@
+use color_eyre::eyre::{eyre, Result};
+
+use crate::grid::{Automaton, Grid};
+use crate::parse::Text;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Location {
+    #[default]
+    Empty,
+    East,
+    South,
+}
+
+impl Location {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Location::Empty),
+            '>' => Some(Location::East),
+            'v' => Some(Location::South),
+            _ => None,
+        }
+    }
+}
+
+/// The east-facing herd moves into any empty space in front of it.
+#[inline]
+fn east(herds: &Grid<Location>, (x, y): (i32, i32)) -> Location {
+    match herds[(x, y)] {
+        Location::Empty if herds[(x - 1, y)] == Location::East => Location::East,
+        Location::East if herds[(x + 1, y)] == Location::Empty => Location::Empty,
+        here => here,
+    }
+}
+
+/// Then the south-facing herd does.
+#[inline]
+fn south(herds: &Grid<Location>, (x, y): (i32, i32)) -> Location {
+    match herds[(x, y)] {
+        Location::Empty if herds[(x, y - 1)] == Location::South => Location::South,
+        Location::South if herds[(x, y + 1)] == Location::Empty => Location::Empty,
+        here => here,
+    }
+}
+
+/// How long to wait for the herds to stop. Some maps keep them going round forever.
+const STEP_LIMIT: usize = 10_000;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input = Grid<Location>;
+    type Options = ();
+    type PartOne = usize;
+    type PartTwo = &'static str;
+
+    fn parse(input: &str) -> Result<Grid<Location>> {
+        let text = Text::new(Self::DAY, input);
+        // The herds leave one edge of the map and come back in at the opposite one.
+        let grid = Grid::parse(&text, input, |c| {
+            Location::parse(c).ok_or("expected `.`, `>` or `v`")
+        })?;
+        Ok(grid.wrapping())
+    }
+
+    fn part_one(grid: &Grid<Location>, _: &()) -> Result<usize> {
+        let mut herds = Automaton::new(grid.clone());
+        herds
+            .settle_by(Some(STEP_LIMIT), |herds| {
+                herds.step(east) + herds.step(south)
+            })
+            .ok_or_else(|| eyre!("the herds still move after {} steps", STEP_LIMIT))
+    }
+
+    /// There's no second puzzle on the last day.
+    fn part_two(_grid: &Grid<Location>, _: &()) -> Result<&'static str> {
+        Ok("(none)")
+    }
+}
+
+pub const EXAMPLE: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
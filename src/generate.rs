@@ -0,0 +1,1006 @@
+//! Random puzzle inputs, for pushing the solvers well past the sizes of the real ones.
+//!
+//! Every generator takes a seed, so a slow or failing input can be made again exactly, and most
+//! take a size saying how much input to make. The inputs are valid in the same ways the real ones
+//! are: day 3's numbers are distinct, day 13's dots never sit on a fold, day 19's scanners can all
+//! be aligned, day 24's program has a model number, and so on.
+
+use std::collections::HashSet;
+
+/// A small, fast pseudo-random number generator ([SplitMix64]). It's not much use for anything
+/// but making up inputs, but the same seed always gives the same numbers on every platform.
+///
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `0..n`. `n` must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "can't pick a number below 0");
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    /// A number from `min..=max`.
+    pub fn range(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "empty range {}..={}", min, max);
+        min + self.below((max - min) as u64 + 1) as i64
+    }
+
+    /// An index into something of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// What a generator's size counts, how big an input to make if no size is given, and the smallest
+/// size the solver can make sense of (day 18 needs two numbers to add, say).
+#[derive(Debug, Clone, Copy)]
+pub struct Size {
+    pub counts: &'static str,
+    pub min: usize,
+    pub default: usize,
+}
+
+/// Makes random inputs for one day.
+pub struct Generator {
+    pub day: u8,
+    /// `None` for days whose input always has the same shape, like day 21's two players.
+    pub size: Option<Size>,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// Makes an input of the given size (ignored if the day doesn't take one, and raised to the
+    /// day's minimum if it's too small). Like the examples, it has no trailing newline.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        let size = self.size.map_or(size, |sizes| size.max(sizes.min));
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+const fn sized(
+    day: u8,
+    counts: &'static str,
+    min: usize,
+    default: usize,
+    generate: fn(&mut Rng, usize) -> String,
+) -> Generator {
+    Generator {
+        day,
+        size: Some(Size {
+            counts,
+            min,
+            default,
+        }),
+        generate,
+    }
+}
+
+const fn fixed(day: u8, generate: fn(&mut Rng, usize) -> String) -> Generator {
+    Generator {
+        day,
+        size: None,
+        generate,
+    }
+}
+
+pub static ALL: [Generator; 25] = [
+    sized(1, "depth measurements", 1, 2000, day01),
+    sized(2, "commands", 1, 1000, day02),
+    sized(3, "diagnostic numbers", 1, 1000, day03),
+    sized(4, "bingo boards", 1, 100, day04),
+    sized(5, "vent lines", 1, 500, day05),
+    sized(6, "lanternfish", 1, 300, day06),
+    sized(7, "crabs", 1, 1000, day07),
+    sized(8, "display entries", 1, 200, day08),
+    sized(9, "rows and columns of the heightmap", 3, 100, day09),
+    sized(10, "lines", 0, 100, day10),
+    sized(11, "rows and columns of octopuses", 1, 10, day11),
+    sized(12, "caves besides start and end", 0, 10, day12),
+    sized(13, "dots", 1, 800, day13),
+    sized(14, "elements in the template", 0, 20, day14),
+    sized(15, "rows and columns of the risk map", 1, 100, day15),
+    sized(16, "packets", 0, 300, day16),
+    sized(17, "distance to the target", 0, 150, day17),
+    sized(18, "snailfish numbers", 2, 100, day18),
+    sized(19, "scanners", 2, 30, day19),
+    sized(20, "rows and columns of the image", 0, 100, day20),
+    fixed(21, day21),
+    sized(22, "reboot steps", 1, 420, day22),
+    fixed(23, day23),
+    fixed(24, day24),
+    sized(25, "rows and columns of sea cucumbers", 1, 139, day25),
+];
+
+/// A square grid of `size * size` random digits.
+fn digits(rng: &mut Rng, size: usize, digit: impl Fn(&mut Rng) -> u8) -> Vec<u8> {
+    (0..size * size).map(|_| digit(rng)).collect()
+}
+
+fn digit_grid(size: usize, digits: &[u8]) -> String {
+    digits
+        .chunks(size.max(1))
+        .map(|row| {
+            row.iter()
+                .map(|&d| char::from(b'0' + d))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A square grid of `size * size` characters, given as bytes.
+fn text_grid(size: usize, cells: &[u8]) -> String {
+    cells
+        .chunks(size.max(1))
+        .map(|row| String::from_utf8_lossy(row).into_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day01(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100, 200);
+    let mut depths = Vec::with_capacity(size);
+    for _ in 0..size {
+        depths.push(depth.to_string());
+        depth = (depth + rng.range(-20, 40)).max(0);
+    }
+    depths.join("\n")
+}
+
+fn day02(rng: &mut Rng, size: usize) -> String {
+    let mut depth = 0;
+    (0..size)
+        .map(|_| {
+            let units = rng.range(1, 9);
+            match rng.below(3) {
+                0 => format!("forward {}", units),
+                1 if depth >= units => {
+                    depth -= units;
+                    format!("up {}", units)
+                }
+                _ => {
+                    depth += units;
+                    format!("down {}", units)
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day03(rng: &mut Rng, size: usize) -> String {
+    // The ratings can only be found if the numbers are all different, so leave plenty of room.
+    let width = (usize::BITS - size.leading_zeros() + 1).max(12) as usize;
+    let mut seen = HashSet::new();
+    let mut numbers = Vec::with_capacity(size);
+    while numbers.len() < size {
+        let number = rng.below(1 << width);
+        if seen.insert(number) {
+            numbers.push(number);
+        }
+    }
+
+    // A column with as many ones as zeros has no most common bit, so flip one of its bits
+    // wherever that doesn't make two numbers the same. That isn't always possible (two numbers
+    // can't agree on every column), and then part one treats the column's bit as a zero.
+    for bit in 0..width {
+        let ones = numbers.iter().filter(|&&n| n >> bit & 1 == 1).count();
+        if ones * 2 != size {
+            continue;
+        }
+        if let Some(i) = numbers
+            .iter()
+            .position(|&n| !seen.contains(&(n ^ 1 << bit)))
+        {
+            seen.remove(&numbers[i]);
+            numbers[i] ^= 1 << bit;
+            seen.insert(numbers[i]);
+        }
+    }
+
+    numbers
+        .iter()
+        .map(|n| format!("{:0width$b}", n, width = width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day04(rng: &mut Rng, size: usize) -> String {
+    let mut draws: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut draws);
+
+    let mut sections = vec![draws
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(",")];
+    for _ in 0..size {
+        let mut numbers: Vec<u32> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+        sections.push(
+            numbers[..25]
+                .chunks(5)
+                .map(|row| {
+                    row.iter()
+                        .map(|n| format!("{:2}", n))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+    sections.join("\n\n")
+}
+
+fn day05(rng: &mut Rng, size: usize) -> String {
+    const DIRECTIONS: [(i64, i64); 8] = [
+        (1, 0),
+        (-1, 0),
+        (0, 1),
+        (0, -1),
+        (1, 1),
+        (1, -1),
+        (-1, 1),
+        (-1, -1),
+    ];
+    // How many steps we can take from `at` in `direction` while staying on the 1000x1000 floor.
+    let room = |at: i64, direction: i64| match direction {
+        1 => 999 - at,
+        -1 => at,
+        _ => i64::MAX,
+    };
+
+    (0..size)
+        .map(|_| loop {
+            let (x1, y1) = (rng.range(0, 999), rng.range(0, 999));
+            let (dx, dy) = *rng.pick(&DIRECTIONS);
+            let longest = room(x1, dx).min(room(y1, dy)).min(500);
+            if longest > 0 {
+                let length = rng.range(1, longest);
+                break format!("{},{} -> {},{}", x1, y1, x1 + dx * length, y1 + dy * length);
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day06(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| rng.range(1, 5).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn day07(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| rng.range(0, 1999).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn day08(rng: &mut Rng, size: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+
+    (0..size)
+        .map(|_| {
+            let mut wiring: Vec<u8> = (b'a'..=b'g').collect();
+            rng.shuffle(&mut wiring);
+            let pattern = |rng: &mut Rng, digit: &str| {
+                let mut segments: Vec<char> = digit
+                    .bytes()
+                    .map(|segment| char::from(wiring[usize::from(segment - b'a')]))
+                    .collect();
+                rng.shuffle(&mut segments);
+                segments.into_iter().collect::<String>()
+            };
+
+            let mut patterns: Vec<String> =
+                DIGITS.iter().map(|digit| pattern(rng, digit)).collect();
+            let outputs: Vec<String> = (0..4)
+                .map(|_| {
+                    let digit = *rng.pick(&DIGITS);
+                    pattern(rng, digit)
+                })
+                .collect();
+            rng.shuffle(&mut patterns);
+            format!("{} | {}", patterns.join(" "), outputs.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day09(rng: &mut Rng, size: usize) -> String {
+    // Enough 9s to wall off lots of basins; with only one in ten there'd be a single huge one.
+    let mut heights = digits(rng, size, |rng| {
+        if rng.chance(0.35) {
+            9
+        } else {
+            rng.range(0, 8) as u8
+        }
+    });
+    // Part two needs three basins, so wall off the top left corner with the second row and
+    // column, which leaves the corner, the rest of the top row and the rest of the left column
+    // in basins of their own.
+    for i in 0..size {
+        heights[size + i] = 9;
+        heights[i * size + 1] = 9;
+    }
+    for corner in [0, 2, 2 * size] {
+        heights[corner] = rng.range(0, 8) as u8;
+    }
+    digit_grid(size, &heights)
+}
+
+fn day10(rng: &mut Rng, size: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    // Completion scores grow five-fold per unclosed chunk, so don't leave more open than fit.
+    const MAX_DEPTH: usize = 20;
+
+    (0..size)
+        .map(|i| {
+            // At least one line has to be incomplete for part two to have a middle score.
+            let incomplete = i == 0 || rng.chance(0.5);
+            let length = rng.range(40, 110) as usize;
+            let corrupt_at = rng.index(length);
+
+            let mut line = String::new();
+            let mut open = Vec::new();
+            let mut corrupted = false;
+            for at in 0..length {
+                if !incomplete && !corrupted && at >= corrupt_at && !open.is_empty() {
+                    let expected = open.pop().unwrap();
+                    let wrong = loop {
+                        let (_, close) = *rng.pick(&PAIRS);
+                        if close != expected {
+                            break close;
+                        }
+                    };
+                    line.push(wrong);
+                    corrupted = true;
+                } else if open.len() == MAX_DEPTH || (!open.is_empty() && rng.chance(0.45)) {
+                    line.push(open.pop().unwrap());
+                } else {
+                    let (start, close) = *rng.pick(&PAIRS);
+                    line.push(start);
+                    open.push(close);
+                }
+            }
+            if open.is_empty() || (!incomplete && !corrupted) {
+                let (start, close) = *rng.pick(&PAIRS);
+                line.push(start);
+                if !incomplete && !corrupted {
+                    line.push(PAIRS.iter().find(|pair| pair.1 != close).unwrap().1);
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day11(rng: &mut Rng, size: usize) -> String {
+    /// Steps the octopuses until they all flash at once, giving up after `limit` steps.
+    fn synchronizes(mut energy: Vec<u8>, size: usize, limit: usize) -> bool {
+        let mut flashing = Vec::new();
+        for _ in 0..limit {
+            let mut flashes = 0;
+            for (i, e) in energy.iter_mut().enumerate() {
+                *e += 1;
+                if *e == 10 {
+                    flashing.push(i);
+                }
+            }
+            while let Some(i) = flashing.pop() {
+                flashes += 1;
+                let (x, y) = (i % size, i / size);
+                for ny in y.saturating_sub(1)..(y + 2).min(size) {
+                    for nx in x.saturating_sub(1)..(x + 2).min(size) {
+                        let neighbor = ny * size + nx;
+                        energy[neighbor] += 1;
+                        if energy[neighbor] == 10 {
+                            flashing.push(neighbor);
+                        }
+                    }
+                }
+            }
+            for e in &mut energy {
+                if *e >= 10 {
+                    *e = 0;
+                }
+            }
+            if flashes == energy.len() {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Most grids of random energies never all flash together, which part two needs, so try some
+    // and keep one that does. Small grids are tried with the full range of energies like the
+    // real input, then (and for big grids, straight away) narrower and narrower bands of
+    // energies, which get in step quickly. If even those fail, every octopus starting with the
+    // same energy always works.
+    let widest = if size <= 20 { 10 } else { 5 };
+    for width in (2..=widest).rev() {
+        for _ in 0..5 {
+            let low = rng.range(0, 10 - width);
+            let energy = digits(rng, size, |rng| rng.range(low, low + width - 1) as u8);
+            if synchronizes(energy.clone(), size, 1000) {
+                return digit_grid(size, &energy);
+            }
+        }
+    }
+    digit_grid(size, &vec![rng.range(0, 9) as u8; size * size])
+}
+
+fn day12(rng: &mut Rng, size: usize) -> String {
+    // Cave names are spreadsheet-style columns: a, b, ..., z, aa, ab, ...
+    let name = |mut index: usize, big: bool| {
+        let mut name = Vec::new();
+        loop {
+            name.push(b'a' + (index % 26) as u8);
+            index /= 26;
+            if index == 0 {
+                break;
+            }
+            index -= 1;
+        }
+        name.reverse();
+        let name = String::from_utf8(name).unwrap();
+        if big {
+            name.to_uppercase()
+        } else {
+            name
+        }
+    };
+
+    let mut caves = vec![("start".to_owned(), false), ("end".to_owned(), false)];
+    let mut index = 0;
+    while caves.len() < size + 2 {
+        // Two big caves next to each other would let a path loop forever, so keep them rarer
+        // and only ever link them to small caves.
+        let big = rng.chance(0.25);
+        let cave = name(index, big);
+        index += 1;
+        if cave != "end" && cave != "start" {
+            caves.push((cave, big));
+        }
+    }
+    rng.shuffle(&mut caves[1..]);
+
+    // Link every cave to one before it so they're all reachable from the start, then add a few
+    // more links to give the paths somewhere to branch.
+    let linkable = |a: usize, b: usize| a != b && !(caves[a].1 && caves[b].1);
+    let mut edges = HashSet::new();
+    for cave in 1..caves.len() {
+        let other = loop {
+            let other = rng.index(cave);
+            if linkable(cave, other) {
+                break other;
+            }
+        };
+        edges.insert((other.min(cave), other.max(cave)));
+    }
+    for _ in 0..size / 2 {
+        let (a, b) = (rng.index(caves.len()), rng.index(caves.len()));
+        if linkable(a, b) {
+            edges.insert((a.min(b), a.max(b)));
+        }
+    }
+
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort_unstable();
+    rng.shuffle(&mut edges);
+    edges
+        .into_iter()
+        .map(|(a, b)| {
+            let (from, to) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            format!("{}-{}", caves[from].0, caves[to].0)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day13(rng: &mut Rng, size: usize) -> String {
+    // Fold lines for paper that ends up 40x6, like the real thing, starting with the first fold.
+    let unfold = |mut width: usize, times: usize| {
+        let mut lines = Vec::new();
+        for _ in 0..times {
+            lines.push(width);
+            width = 2 * width + 1;
+        }
+        lines.reverse();
+        (width, lines)
+    };
+    let (width, x_folds) = unfold(40, 5);
+    let (height, y_folds) = unfold(6, 7);
+
+    // A dot that lands on a fold line at any point would vanish, which the puzzle never does.
+    let on_a_fold = |mut at: usize, folds: &[usize]| {
+        folds.iter().any(|&fold| {
+            if at > fold {
+                at = 2 * fold - at;
+            }
+            at == fold
+        })
+    };
+    let free =
+        |length: usize, folds: &[usize]| (0..length).filter(|&at| !on_a_fold(at, folds)).count();
+    let size = size.min(free(width, &x_folds) * free(height, &y_folds));
+    let mut dots = HashSet::new();
+    while dots.len() < size {
+        let dot = (rng.index(width), rng.index(height));
+        if !on_a_fold(dot.0, &x_folds) && !on_a_fold(dot.1, &y_folds) {
+            dots.insert(dot);
+        }
+    }
+    let mut dots: Vec<_> = dots.into_iter().collect();
+    dots.sort_unstable();
+    rng.shuffle(&mut dots);
+
+    // Interleave the two axes' folds, keeping each axis in order.
+    let mut folds = Vec::new();
+    let (mut xs, mut ys) = (x_folds.iter(), y_folds.iter());
+    let (mut x_left, mut y_left) = (x_folds.len(), y_folds.len());
+    while x_left + y_left > 0 {
+        if rng.below((x_left + y_left) as u64) < x_left as u64 {
+            folds.push(format!("fold along x={}", xs.next().unwrap()));
+            x_left -= 1;
+        } else {
+            folds.push(format!("fold along y={}", ys.next().unwrap()));
+            y_left -= 1;
+        }
+    }
+
+    let dots: Vec<_> = dots.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+    format!("{}\n\n{}", dots.join("\n"), folds.join("\n"))
+}
+
+fn day14(rng: &mut Rng, size: usize) -> String {
+    const ELEMENTS: [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+
+    let template: String = (0..size.max(2)).map(|_| *rng.pick(&ELEMENTS)).collect();
+    let mut rules = Vec::new();
+    for first in ELEMENTS {
+        for second in ELEMENTS {
+            rules.push(format!("{}{} -> {}", first, second, rng.pick(&ELEMENTS)));
+        }
+    }
+    rng.shuffle(&mut rules);
+    format!("{}\n\n{}", template, rules.join("\n"))
+}
+
+fn day15(rng: &mut Rng, size: usize) -> String {
+    let risks = digits(rng, size, |rng| rng.range(1, 9) as u8);
+    digit_grid(size, &risks)
+}
+
+fn day16(rng: &mut Rng, size: usize) -> String {
+    fn push(bits: &mut Vec<bool>, value: u64, width: u32) {
+        bits.extend((0..width).rev().map(|bit| value >> bit & 1 == 1));
+    }
+
+    /// Makes a packet made of `budget` packets in all, returning its bits and value.
+    fn packet(rng: &mut Rng, budget: usize) -> (Vec<bool>, u64) {
+        let mut bits = Vec::new();
+        push(&mut bits, rng.below(8), 3);
+
+        if budget == 1 {
+            let value = rng.below(1 << 20);
+            push(&mut bits, 4, 3);
+            let groups = (64 - value.leading_zeros()).max(1).div_ceil(4);
+            for group in (0..groups).rev() {
+                push(&mut bits, u64::from(group > 0), 1);
+                push(&mut bits, value >> (4 * group) & 0xf, 4);
+            }
+            return (bits, value);
+        }
+
+        // Split the rest of the budget between at least two subpackets where we can, so the
+        // packets nest about log(size) deep.
+        let comparison = budget >= 3 && rng.chance(0.2);
+        let count = match budget - 1 {
+            _ if comparison => 2,
+            1 => 1,
+            rest => rng.range(2, rest.min(8) as i64) as usize,
+        };
+        let mut cuts: Vec<usize> = (1..count).map(|_| 1 + rng.index(budget - 2)).collect();
+        cuts.extend([0, budget - 1]);
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let mut subpackets = Vec::new();
+        let mut values = Vec::new();
+        for pair in cuts.windows(2) {
+            let (sub_bits, value) = packet(rng, pair[1] - pair[0]);
+            subpackets.extend(sub_bits);
+            values.push(value);
+        }
+
+        let (type_id, value) = if comparison || values.len() == 2 && rng.chance(0.2) {
+            let type_id = rng.range(5, 7) as u64;
+            let value = match type_id {
+                5 => values[0] > values[1],
+                6 => values[0] < values[1],
+                _ => values[0] == values[1],
+            };
+            (type_id, u64::from(value))
+        } else {
+            // Products are kept small enough that even a sum of millions of them fits in a u64.
+            let product = values
+                .iter()
+                .try_fold(1u64, |product, &v| product.checked_mul(v))
+                .filter(|&product| product < 1 << 40);
+            match (rng.below(4), product) {
+                (1, Some(product)) => (1, product),
+                (2, _) => (2, *values.iter().min().unwrap()),
+                (3, _) => (3, *values.iter().max().unwrap()),
+                _ => (0, values.iter().sum()),
+            }
+        };
+        push(&mut bits, type_id, 3);
+
+        if subpackets.len() < 1 << 15 && rng.chance(0.5) {
+            push(&mut bits, 0, 1);
+            push(&mut bits, subpackets.len() as u64, 15);
+        } else {
+            push(&mut bits, 1, 1);
+            push(&mut bits, values.len() as u64, 11);
+        }
+        bits.extend(subpackets);
+        (bits, value)
+    }
+
+    let (mut bits, _) = packet(rng, size.max(1));
+    bits.resize(bits.len().div_ceil(8) * 8, false);
+    bits.chunks(4)
+        .map(|nibble| {
+            let digit = nibble
+                .iter()
+                .fold(0, |digit, &bit| digit << 1 | u32::from(bit));
+            char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect()
+}
+
+fn day17(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(10) as i64;
+    let x_min = rng.range(size, 2 * size);
+    let y_max = -rng.range(size / 2, size);
+    format!(
+        "target area: x={}..{}, y={}..{}",
+        x_min,
+        x_min + rng.range(size / 10, size / 4),
+        y_max - rng.range(size / 10, size / 4),
+        y_max
+    )
+}
+
+fn day18(rng: &mut Rng, size: usize) -> String {
+    /// A reduced number, so nested at most four pairs deep, but usually as deep as that allows.
+    fn snailfish(rng: &mut Rng, depth: usize) -> String {
+        let element = |rng: &mut Rng| {
+            if depth < 4 && rng.chance(0.75) {
+                snailfish(rng, depth + 1)
+            } else {
+                rng.range(0, 9).to_string()
+            }
+        };
+        let left = element(rng);
+        format!("[{},{}]", left, element(rng))
+    }
+
+    (0..size)
+        .map(|_| snailfish(rng, 1))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day19(rng: &mut Rng, size: usize) -> String {
+    type Point = [i64; 3];
+    const RANGE: i64 = 1000;
+
+    let distance = |a: Point, b: Point| (0..3).map(|i| (a[i] - b[i]).abs()).sum::<i64>();
+    // A beacon inside the cube `min..=max`.
+    let beacon = |rng: &mut Rng, min: Point, max: Point| -> Point {
+        [
+            rng.range(min[0], max[0]),
+            rng.range(min[1], max[1]),
+            rng.range(min[2], max[2]),
+        ]
+    };
+    let around = |at: Point| {
+        (
+            [at[0] - RANGE, at[1] - RANGE, at[2] - RANGE],
+            [at[0] + RANGE, at[1] + RANGE, at[2] + RANGE],
+        )
+    };
+
+    // Each scanner after the first overlaps a scanner before it, seeing 12 of the same beacons.
+    // Scanners overlap at most three others, so no scanner sees so many beacons that the beacons'
+    // distances to each other start matching by chance.
+    let mut positions: Vec<Point> = vec![[0; 3]];
+    let mut seen: Vec<Vec<Point>> = vec![Vec::new()];
+    let mut open = vec![0, 0];
+    let mut all = HashSet::new();
+    for scanner in 0..size.max(1) {
+        if scanner > 0 {
+            let parent = open.swap_remove(rng.index(open.len()));
+            let offset = [(); 3].map(|_| rng.range(-1100, 1100));
+            let position = [0, 1, 2].map(|i| positions[parent][i] + offset[i]);
+            let (parent_min, parent_max) = around(positions[parent]);
+            let (min, max) = around(position);
+            let overlap_min = [0, 1, 2].map(|i| parent_min[i].max(min[i]));
+            let overlap_max = [0, 1, 2].map(|i| parent_max[i].min(max[i]));
+
+            // Shared beacons need distinct distances to each other, or they'd match fewer
+            // than 11 of their neighbours.
+            let mut shared: Vec<Point> = Vec::new();
+            let mut distances = HashSet::new();
+            while shared.len() < 12 {
+                let b = beacon(rng, overlap_min, overlap_max);
+                let new: Vec<_> = shared.iter().map(|&other| distance(b, other)).collect();
+                let unique: HashSet<_> = new.iter().collect();
+                if unique.len() == new.len()
+                    && new.iter().all(|d| !distances.contains(d))
+                    && !all.contains(&b)
+                {
+                    distances.extend(new);
+                    all.insert(b);
+                    shared.push(b);
+                }
+            }
+            seen[parent].extend(&shared);
+            positions.push(position);
+            seen.push(shared);
+            open.extend([scanner, scanner]);
+        }
+
+        let (min, max) = around(positions[scanner]);
+        let mut own = 0;
+        while own < 13 {
+            let b = beacon(rng, min, max);
+            if all.insert(b) {
+                seen[scanner].push(b);
+                own += 1;
+            }
+        }
+    }
+
+    // Each scanner reports its beacons relative to itself, facing one of 24 ways.
+    let mut rotations = Vec::new();
+    for axes in [
+        [0, 1, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [1, 0, 2],
+        [0, 2, 1],
+        [2, 1, 0],
+    ] {
+        let odd = axes != [0, 1, 2] && axes != [1, 2, 0] && axes != [2, 0, 1];
+        for signs in 0..8 {
+            let negated = (signs as u32).count_ones() % 2 == 1;
+            if negated == odd {
+                rotations.push((axes, signs));
+            }
+        }
+    }
+    seen.iter_mut()
+        .zip(&positions)
+        .enumerate()
+        .map(|(scanner, (beacons, position))| {
+            let (axes, signs) = *rng.pick(&rotations);
+            rng.shuffle(beacons);
+            let mut report = vec![format!("--- scanner {} ---", scanner)];
+            for beacon in beacons.iter() {
+                let relative = [0, 1, 2].map(|i| {
+                    let sign = if signs >> i & 1 == 1 { -1 } else { 1 };
+                    sign * (beacon[axes[i]] - position[axes[i]])
+                });
+                report.push(format!("{},{},{}", relative[0], relative[1], relative[2]));
+            }
+            report.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let pixel = |rng: &mut Rng| if rng.chance(0.5) { '#' } else { '.' };
+    let mut algorithm: Vec<char> = (0..512).map(|_| pixel(rng)).collect();
+    // If the infinite dark background lights up, it has to go dark again on the next step, or
+    // there'd be infinitely many lit pixels to count.
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+
+    let image: Vec<String> = (0..size)
+        .map(|_| (0..size).map(|_| pixel(rng)).collect())
+        .collect();
+    format!(
+        "{}\n\n{}",
+        algorithm.into_iter().collect::<String>(),
+        image.join("\n")
+    )
+}
+
+fn day21(rng: &mut Rng, _: usize) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}",
+        rng.range(1, 10),
+        rng.range(1, 10)
+    )
+}
+
+fn day22(rng: &mut Rng, size: usize) -> String {
+    // Like the real input, the first steps stay inside the -50..50 initialization region (and
+    // the first of them turns cubes on), and the rest are far bigger and lie entirely outside it
+    // on at least one axis.
+    let range = |rng: &mut Rng, reach: i64, most: i64| {
+        let min = rng.range(-reach, reach - 1);
+        let max = (min + rng.range(1, most)).min(reach);
+        (min, max)
+    };
+    let outside = |rng: &mut Rng| {
+        let len = rng.range(1, 30_000);
+        let min = rng.range(51, 100_000 - len);
+        match rng.chance(0.5) {
+            true => (min, min + len),
+            false => (-min - len, -min),
+        }
+    };
+    (0..size)
+        .map(|step| {
+            let mut axes = [(0, 0); 3];
+            if step < 20 {
+                axes = axes.map(|_| range(rng, 50, 50));
+            } else {
+                axes = axes.map(|_| range(rng, 100_000, 30_000));
+                axes[rng.index(3)] = outside(rng);
+            }
+            let [x, y, z] = axes;
+            format!(
+                "{} x={}..{},y={}..{},z={}..{}",
+                if step == 0 || rng.chance(0.6) {
+                    "on"
+                } else {
+                    "off"
+                },
+                x.0,
+                x.1,
+                y.0,
+                y.1,
+                z.0,
+                z.1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day23(rng: &mut Rng, _: usize) -> String {
+    let mut amphipods: Vec<char> = "AABBCCDD".chars().collect();
+    rng.shuffle(&mut amphipods);
+    let row = |row: &[char]| {
+        row.iter()
+            .map(char::to_string)
+            .collect::<Vec<_>>()
+            .join("#")
+    };
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########",
+        row(&amphipods[..4]),
+        row(&amphipods[4..])
+    )
+}
+
+fn day24(rng: &mut Rng, _: usize) -> String {
+    // Each block either pushes a digit (plus an offset) onto z as a base-26 stack, or pops one,
+    // which only keeps z from growing if the new digit matches it. The pushes and pops pair up
+    // like brackets, and each pair's offsets stay within 8 so some pair of digits matches.
+    let mut blocks = Vec::new();
+    let mut stack = Vec::new();
+    let mut pushes_left = 7;
+    for _ in 0..14 {
+        if pushes_left > 0 && (stack.is_empty() || rng.chance(0.5)) {
+            let offset = rng.range(0, 16);
+            stack.push(offset);
+            blocks.push((1, rng.range(10, 15), offset));
+            pushes_left -= 1;
+        } else {
+            let pushed = stack.pop().unwrap();
+            blocks.push((26, rng.range(-8, 8) - pushed, rng.range(0, 16)));
+        }
+    }
+
+    blocks
+        .into_iter()
+        .map(|(divisor, check, offset)| {
+            format!(
+                "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                 mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+                 mul y x\nadd z y",
+                divisor, check, offset
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day25(rng: &mut Rng, size: usize) -> String {
+    /// Moves the herds until they stop, giving up after `limit` steps.
+    fn settles(mut herds: Vec<u8>, size: usize, limit: usize) -> bool {
+        for _ in 0..limit {
+            let mut moved = false;
+            for (herd, dx, dy) in [(b'>', 1, 0), (b'v', 0, 1)] {
+                let before = herds.clone();
+                for y in 0..size {
+                    for x in 0..size {
+                        let ahead = (y + dy) % size * size + (x + dx) % size;
+                        if before[y * size + x] == herd && before[ahead] == b'.' {
+                            herds[y * size + x] = b'.';
+                            herds[ahead] = herd;
+                            moved = true;
+                        }
+                    }
+                }
+            }
+            if !moved {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Part one needs the herds to stop, and on small maps they often chase each other round
+    // forever, so try some maps and keep one where they stop. The first ones are 40% empty like
+    // the real input, and then each round of tries leaves fewer empty spaces, since crowded
+    // maps jam up sooner. A map with none at all never moves.
+    for empty in (1..=4).rev() {
+        for _ in 0..5 {
+            let herds: Vec<u8> = (0..size * size)
+                .map(|_| match rng.below(10) < empty {
+                    true => b'.',
+                    false => *rng.pick(b">v"),
+                })
+                .collect();
+            if settles(herds.clone(), size, 1000) {
+                return text_grid(size, &herds);
+            }
+        }
+    }
+    let herds: Vec<u8> = (0..size * size).map(|_| *rng.pick(b">v")).collect();
+    text_grid(size, &herds)
+}
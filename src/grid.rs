@@ -1,12 +1,22 @@
-use std::ops::{Index, IndexMut};
+use crate::parse::{ParseError, Text};
 
-#[derive(Debug)]
+use bitvec::prelude::*;
+use pathfinding::prelude::astar;
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, RangeInclusive, Sub, SubAssign};
+
+#[derive(Debug, Clone)]
 pub struct Grid<T> {
     pub x_min: i32,
     pub x_len: i32,
     pub y_min: i32,
     pub y_len: i32,
     pub map: Vec<T>,
+    /// Whether coordinates off one edge come back on at the opposite one.
+    pub wrap: bool,
 }
 
 impl<T> Grid<T>
@@ -22,29 +32,287 @@ where
             y_min,
             y_len,
             map,
+            wrap: false,
         }
     }
 
-    fn coords_to_index(&self, (x, y): (i32, i32)) -> Option<usize> {
-        if x < self.x_min || y < self.y_min {
-            return None;
+    /// Makes the grid a torus, so every coordinate names a cell.
+    pub fn wrapping(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Reads a map with a row per line and a cell per character, decoding each character with
+    /// `decode`. The grid starts at (0, 0) and is as big as the map. Rows that aren't as long as
+    /// the first one are reported, as are characters `decode` rejects.
+    pub fn parse<'a, E: Display>(
+        text: &Text<'a>,
+        map: &'a str,
+        mut decode: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let width = map.split("\n").next().map_or(0, |row| row.chars().count());
+        if width == 0 {
+            return Err(text.error(map, "expected a map"));
         }
 
-        let x = x - self.x_min;
-        let y = y - self.y_min;
+        let mut cells = Vec::with_capacity(map.len());
+        let mut height = 0;
+        for row in map.split("\n") {
+            let mut row_width = 0;
+            for (i, c) in row.char_indices() {
+                cells.push(decode(c).map_err(|e| text.error(&row[i..], e))?);
+                row_width += 1;
+            }
+            if row_width != width {
+                return Err(text.error(row, format!("expected {} cells like the first row", width)));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            x_min: 0,
+            x_len: width as i32,
+            y_min: 0,
+            y_len: height,
+            map: cells,
+            wrap: false,
+        })
+    }
+
+    /// The coordinates of the cell at `coords`: the same ones if they're on the grid, the ones
+    /// they wrap around to on a wrapping grid, or `None`.
+    #[inline]
+    pub fn normalize(&self, coords: impl Into<Point>) -> Option<(i32, i32)> {
+        self.locate(coords).map(|(coords, _)| coords)
+    }
+
+    /// Where `coords` would wrap around to, whether or not the grid wraps.
+    fn wrapped(&self, (x, y): (i32, i32)) -> (i32, i32) {
+        (
+            self.x_min + wrap_offset(x - self.x_min, self.x_len),
+            self.y_min + wrap_offset(y - self.y_min, self.y_len),
+        )
+    }
+
+    #[inline]
+    fn coords_to_index(&self, coords: impl Into<Point>) -> Option<usize> {
+        self.locate(coords).map(|(_, index)| index)
+    }
+
+    /// The cell at `coords`, as its normalized coordinates and its index in `map`.
+    #[inline]
+    fn locate(&self, coords: impl Into<Point>) -> Option<((i32, i32), usize)> {
+        let Point { x, y } = coords.into();
+        let (dx, dy) = (x - self.x_min, y - self.y_min);
+        // Negative offsets turn into huge unsigned ones, so one comparison checks each axis.
+        if dx as u32 >= self.x_len as u32 || dy as u32 >= self.y_len as u32 {
+            return self.locate_wrapped((x, y));
+        }
+        Some(((x, y), (dy * self.x_len + dx) as usize))
+    }
 
-        if x >= self.x_len || y >= self.y_len {
+    /// `locate` for coordinates off the grid, kept out of line so that the usual case is small
+    /// enough to inline into the loops over cells.
+    #[inline(never)]
+    fn locate_wrapped(&self, coords: (i32, i32)) -> Option<((i32, i32), usize)> {
+        if !self.wrap || self.map.is_empty() {
             return None;
         }
+        let (x, y) = self.wrapped(coords);
+        let index = ((y - self.y_min) * self.x_len + (x - self.x_min)) as usize;
+        Some(((x, y), index))
+    }
+
+    /// The cells around `coords`, including diagonally, leaving out any off the grid.
+    pub fn neighbors(&self, coords: (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        coords.neighbors().filter_map(|n| self.normalize(n))
+    }
+
+    /// The cells beside, above and below `coords`, leaving out any off the grid.
+    pub fn neighbors4(&self, coords: (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        coords.neighbors4().filter_map(|n| self.normalize(n))
+    }
+
+    /// The cells beside, above and below `coords` that are on the grid, with their contents, in
+    /// reading order. On a small wrapping grid where two directions lead to the same cell, it
+    /// comes up once.
+    pub fn neighbors4_of(&self, coords: (i32, i32)) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.distinct_adjacent(coords, Connectivity::Four)
+            .map(|(n, index)| (n, &self.map[index]))
+    }
+
+    /// The cells around `coords` that are on the grid, including diagonally, with their
+    /// contents, like `neighbors4_of`.
+    pub fn neighbors8_of(&self, coords: (i32, i32)) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.distinct_adjacent(coords, Connectivity::Eight)
+            .map(|(n, index)| (n, &self.map[index]))
+    }
+
+    /// Like `neighbors4_of`, but the contents can be changed.
+    pub fn neighbors4_of_mut(
+        &mut self,
+        coords: (i32, i32),
+    ) -> impl Iterator<Item = ((i32, i32), &mut T)> {
+        let neighbors = self.sorted_adjacent(coords, Connectivity::Four);
+        self.cells_mut(neighbors)
+    }
+
+    /// Like `neighbors8_of`, but the contents can be changed.
+    pub fn neighbors8_of_mut(
+        &mut self,
+        coords: (i32, i32),
+    ) -> impl Iterator<Item = ((i32, i32), &mut T)> {
+        let neighbors = self.sorted_adjacent(coords, Connectivity::Eight);
+        self.cells_mut(neighbors)
+    }
+
+    /// Like `adjacent`, but each cell just once and in reading order, the way the `_mut`
+    /// versions give them. Only a wrapping grid can reach a cell two ways, or out of order, so other grids
+    /// skip the sorting.
+    fn distinct_adjacent(
+        &self,
+        coords: (i32, i32),
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = ((i32, i32), usize)> + '_ {
+        if self.wrap {
+            Adjacent::Sorted(self.sorted_adjacent(coords, connectivity))
+        } else {
+            Adjacent::Plain(self.adjacent(coords, connectivity))
+        }
+    }
+
+    /// `adjacent` gathered into an array, sorted by index without repeats.
+    fn sorted_adjacent(
+        &self,
+        coords: (i32, i32),
+        connectivity: Connectivity,
+    ) -> std::iter::Take<std::array::IntoIter<((i32, i32), usize), 8>> {
+        let mut found = [((0, 0), 0); 8];
+        let mut len = 0;
+        for neighbor in self.adjacent(coords, connectivity) {
+            found[len] = neighbor;
+            len += 1;
+        }
+        found[..len].sort_unstable_by_key(|&(_, index)| index);
+        let mut distinct = 0;
+        for i in 0..len {
+            if distinct == 0 || found[i].1 != found[distinct - 1].1 {
+                found[distinct] = found[i];
+                distinct += 1;
+            }
+        }
+        found.into_iter().take(distinct)
+    }
+
+    /// Borrows each of `cells`, which have to be given by their indexes in `map`, in increasing
+    /// order and without repeats.
+    fn cells_mut(
+        &mut self,
+        cells: impl Iterator<Item = ((i32, i32), usize)>,
+    ) -> impl Iterator<Item = ((i32, i32), &mut T)> {
+        // Split each cell off the front of what's left, so every borrow is of a different part
+        // of the map.
+        let mut rest = &mut self.map[..];
+        let mut start = 0;
+        cells.map(move |(coords, index)| {
+            let (cell, after) = std::mem::take(&mut rest)[index - start..]
+                .split_first_mut()
+                .unwrap();
+            rest = after;
+            start = index + 1;
+            (coords, cell)
+        })
+    }
+
+    /// The `K` by `K` square of cells centred on `coords`, row by row. With an even `K` the
+    /// centre is the cell just below and right of the middle. `edge` says what's seen past the
+    /// edge of the grid, even on a wrapping grid.
+    pub fn window<const K: usize>(&self, (x, y): (i32, i32), edge: &Edge<T>) -> [[T; K]; K] {
+        let reach = (K / 2) as i32;
+        let bounds = self.bounds();
+        std::array::from_fn(|dy| {
+            std::array::from_fn(|dx| {
+                let coords = (x + dx as i32 - reach, y + dy as i32 - reach);
+                match edge {
+                    _ if bounds.contains(coords) => self[coords].clone(),
+                    Edge::Constant(value) => value.clone(),
+                    Edge::Wrap => self[self.wrapped(coords)].clone(),
+                }
+            })
+        })
+    }
+
+    /// Every cell with its window; see `window`.
+    pub fn windows<const K: usize>(
+        &self,
+        edge: Edge<T>,
+    ) -> impl Iterator<Item = ((i32, i32), [[T; K]; K])> + '_ {
+        self.cells()
+            .map(move |coords| (coords, self.window(coords, &edge)))
+    }
+
+    /// Like `get`, but says which cell was asked for and where the grid is when it's missing.
+    pub fn try_get(&self, coords: impl Into<Point>) -> Result<&T, OffGrid> {
+        let index = self.try_index(coords.into())?;
+        Ok(&self.map[index])
+    }
+
+    pub fn try_get_mut(&mut self, coords: impl Into<Point>) -> Result<&mut T, OffGrid> {
+        let index = self.try_index(coords.into())?;
+        Ok(&mut self.map[index])
+    }
+
+    /// The cell at `coords` without checking it's on the grid, for hot loops. Wrapping grids
+    /// don't wrap here.
+    ///
+    /// # Safety
+    ///
+    /// `coords` has to be on the grid.
+    pub unsafe fn get_unchecked(&self, coords: impl Into<Point>) -> &T {
+        let Point { x, y } = coords.into();
+        debug_assert!(
+            self.bounds().contains((x, y)),
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        let index = ((y - self.y_min) * self.x_len + (x - self.x_min)) as usize;
+        // SAFETY: the caller promises the cell is on the grid, so the index is in `map`.
+        unsafe { self.map.get_unchecked(index) }
+    }
+
+    fn try_index(&self, Point { x, y }: Point) -> Result<usize, OffGrid> {
+        self.coords_to_index((x, y)).ok_or_else(|| OffGrid {
+            coords: (x.into(), y.into()),
+            bounds: self.bounds(),
+        })
+    }
+
+    #[inline]
+    #[track_caller]
+    fn checked_index(&self, coords: impl Into<Point>) -> usize {
+        let Point { x, y } = coords.into();
+        match self.coords_to_index((x, y)) {
+            Some(index) => index,
+            None => self.off_grid((x.into(), y.into())),
+        }
+    }
 
-        Some((y * self.x_len + x) as usize)
+    #[cold]
+    #[track_caller]
+    fn off_grid(&self, coords: (i64, i64)) -> ! {
+        let error = OffGrid {
+            coords,
+            bounds: self.bounds(),
+        };
+        panic!("{}", error)
     }
 
-    pub fn get(&self, coords: (i32, i32)) -> Option<&T> {
+    pub fn get(&self, coords: impl Into<Point>) -> Option<&T> {
         self.coords_to_index(coords).map(|index| &self.map[index])
     }
 
-    pub fn get_mut(&mut self, coords: (i32, i32)) -> Option<&mut T> {
+    pub fn get_mut(&mut self, coords: impl Into<Point>) -> Option<&mut T> {
         self.coords_to_index(coords)
             .map(|index| &mut self.map[index])
     }
@@ -60,15 +328,335 @@ where
         }
     }
 
-    pub fn cells(&self) -> Cells {
-        Cells {
-            x: self.x_min,
-            y: self.y_min,
+    /// Draws the grid as text, with `cell` picking each cell's character. Call `.to_string()` on
+    /// the result, or print it.
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> Render<'_, T, F> {
+        Render {
+            grid: self,
+            cell,
+            labels: false,
+            x: self.x_min..=self.x_min + self.x_len - 1,
+            y: self.y_min..=self.y_min + self.y_len - 1,
+        }
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds {
             x_min: self.x_min,
             x_max: self.x_min + self.x_len - 1,
+            y_min: self.y_min,
             y_max: self.y_min + self.y_len - 1,
         }
     }
+
+    pub fn cells(&self) -> Cells {
+        self.bounds().cells()
+    }
+
+    /// Each row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.map.chunks(self.x_len.max(1) as usize)
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.map.chunks_mut(self.x_len.max(1) as usize)
+    }
+
+    /// The cells in column `x`, top to bottom.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        let x = x - self.x_min;
+        // A column off the grid skips everything.
+        let start = match (0..self.x_len).contains(&x) {
+            true => x as usize,
+            false => self.map.len(),
+        };
+        self.map[start..].iter().step_by(self.x_len.max(1) as usize)
+    }
+
+    /// Each column, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (self.x_min..self.x_min + self.x_len).map(|x| self.column(x))
+    }
+
+    /// The part of the grid within `bounds`, which keeps the grid's coordinates.
+    pub fn view(&self, bounds: Bounds) -> View<'_, T> {
+        View {
+            bounds: bounds.intersect(self.bounds()),
+            grid: self,
+        }
+    }
+
+    pub fn view_mut(&mut self, bounds: Bounds) -> ViewMut<'_, T> {
+        ViewMut {
+            bounds: bounds.intersect(self.bounds()),
+            grid: self,
+        }
+    }
+
+    /// Swaps rows for columns, so the cell at (x, y) moves to (y, x).
+    pub fn transpose(&self) -> Self {
+        let mut grid = self.reshaped(self.y_min, self.y_len, self.x_min, self.x_len);
+        for (x, y) in self.cells() {
+            grid[(y, x)] = self[(x, y)].clone();
+        }
+        grid
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_x(&self) -> Self {
+        let mut grid = self.clone();
+        for (x, y) in self.cells() {
+            let x_mirror = 2 * self.x_min + self.x_len - 1 - x;
+            grid[(x_mirror, y)] = self[(x, y)].clone();
+        }
+        grid
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_y(&self) -> Self {
+        let mut grid = self.clone();
+        for (x, y) in self.cells() {
+            let y_mirror = 2 * self.y_min + self.y_len - 1 - y;
+            grid[(x, y_mirror)] = self[(x, y)].clone();
+        }
+        grid
+    }
+
+    /// Turns the grid a quarter turn clockwise about its top left corner, which stays where it
+    /// is.
+    pub fn rotate90(&self) -> Self {
+        let mut grid = self.reshaped(self.x_min, self.y_len, self.y_min, self.x_len);
+        for (x, y) in self.cells() {
+            let x_offset = self.y_len - 1 - (y - self.y_min);
+            let y_offset = x - self.x_min;
+            grid[(self.x_min + x_offset, self.y_min + y_offset)] = self[(x, y)].clone();
+        }
+        grid
+    }
+
+    /// Adds `margin` cells of `fill` on every side. The origin moves out with the edge.
+    pub fn grow(&self, margin: i32, fill: T) -> Self {
+        let mut grid = self.reshaped(
+            self.x_min - margin,
+            self.x_len + 2 * margin,
+            self.y_min - margin,
+            self.y_len + 2 * margin,
+        );
+        grid.map.fill(fill);
+        for coords in self.cells() {
+            grid[coords] = self[coords].clone();
+        }
+        grid
+    }
+
+    /// Keeps just the cells within `bounds`, with their coordinates unchanged.
+    pub fn crop(&self, bounds: Bounds) -> Self {
+        let bounds = bounds.intersect(self.bounds());
+        let mut grid = self.reshaped(
+            bounds.x_min,
+            (bounds.x_max - bounds.x_min + 1).max(0),
+            bounds.y_min,
+            (bounds.y_max - bounds.y_min + 1).max(0),
+        );
+        for (row, from) in grid.rows_mut().zip(self.view(bounds).rows()) {
+            row.clone_from_slice(from);
+        }
+        grid
+    }
+
+    /// Lays out `across` by `down` copies of the grid, starting at its origin. `transform` gives
+    /// each cell of each copy from the original cell and the copy's column and row, counting
+    /// from 0.
+    pub fn tile(&self, across: i32, down: i32, transform: impl Fn(i32, i32, &T) -> T) -> Self {
+        let mut grid = self.reshaped(
+            self.x_min,
+            across * self.x_len,
+            self.y_min,
+            down * self.y_len,
+        );
+        for tile_y in 0..down {
+            for tile_x in 0..across {
+                for (x, y) in self.cells() {
+                    let to = (x + tile_x * self.x_len, y + tile_y * self.y_len);
+                    grid[to] = transform(tile_x, tile_y, &self[(x, y)]);
+                }
+            }
+        }
+        grid
+    }
+
+    /// The cells reachable from `start` by stepping between `passable` cells, starting with
+    /// `start` itself. There are none if `start` isn't passable or isn't on the grid.
+    pub fn flood_fill(
+        &self,
+        start: (i32, i32),
+        connectivity: Connectivity,
+        passable: impl Fn(&T) -> bool,
+    ) -> Vec<(i32, i32)> {
+        let mut seen = self.reshaped(self.x_min, self.x_len, self.y_min, self.y_len);
+        self.fill_from(start, connectivity, &passable, &mut seen)
+    }
+
+    /// Splits the `passable` cells into regions of cells connected to each other.
+    pub fn regions(&self, connectivity: Connectivity, passable: impl Fn(&T) -> bool) -> Regions {
+        let mut seen: Grid<bool> = self.reshaped(self.x_min, self.x_len, self.y_min, self.y_len);
+        let mut labels = self.reshaped(self.x_min, self.x_len, self.y_min, self.y_len);
+        let mut regions = Vec::new();
+
+        for start in self.cells() {
+            if seen[start] {
+                continue;
+            }
+            let cells = self.fill_from(start, connectivity, &passable, &mut seen);
+            let Some(&(x, y)) = cells.first() else {
+                continue;
+            };
+
+            let label = regions.len();
+            let mut bounds = Bounds {
+                x_min: x,
+                x_max: x,
+                y_min: y,
+                y_max: y,
+            };
+            for &(x, y) in &cells {
+                labels[(x, y)] = Some(label);
+                bounds.x_min = bounds.x_min.min(x);
+                bounds.x_max = bounds.x_max.max(x);
+                bounds.y_min = bounds.y_min.min(y);
+                bounds.y_max = bounds.y_max.max(y);
+            }
+            regions.push(Region {
+                label,
+                size: cells.len(),
+                bounds,
+                cells,
+            });
+        }
+
+        Regions { labels, regions }
+    }
+
+    /// Flood fills from `start`, skipping and marking cells in `seen`.
+    fn fill_from(
+        &self,
+        start: (i32, i32),
+        connectivity: Connectivity,
+        passable: &impl Fn(&T) -> bool,
+        seen: &mut Grid<bool>,
+    ) -> Vec<(i32, i32)> {
+        let mut cells = Vec::new();
+        let Some(start) = self.normalize(start) else {
+            return cells;
+        };
+        if !passable(&self[start]) {
+            return cells;
+        }
+
+        seen[start] = true;
+        let mut stack = vec![start];
+        while let Some(coords) = stack.pop() {
+            cells.push(coords);
+            for (neighbor, index) in self.adjacent(coords, connectivity) {
+                if !seen.map[index] && passable(&self.map[index]) {
+                    seen.map[index] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+        cells
+    }
+
+    /// The cells next to `coords` on the grid, with their indexes in `map` (which are the same
+    /// in any grid of this shape).
+    fn adjacent(
+        &self,
+        coords: (i32, i32),
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = ((i32, i32), usize)> + '_ {
+        NeighborsIter::new(connectivity.directions(), coords)
+            .filter_map(|n: (i32, i32)| self.locate(n))
+    }
+
+    /// How many steps it takes to get from `start` to each cell, stepping between `passable`
+    /// cells, or `None` for cells that can't be reached.
+    pub fn distances(
+        &self,
+        start: (i32, i32),
+        connectivity: Connectivity,
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<usize>> {
+        let mut distances = self.reshaped(self.x_min, self.x_len, self.y_min, self.y_len);
+        let Some(start) = self.normalize(start) else {
+            return distances;
+        };
+        if !passable(&self[start]) {
+            return distances;
+        }
+
+        distances[start] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((coords, steps)) = queue.pop_front() {
+            for (neighbor, index) in self.adjacent(coords, connectivity) {
+                if distances.map[index].is_none() && passable(&self.map[index]) {
+                    distances.map[index] = Some(steps + 1);
+                    queue.push_back((neighbor, steps + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// The cheapest route from `start` to `goal`, where stepping onto a cell costs what `cost`
+    /// says, and cells it gives `None` for can't be entered.
+    pub fn shortest_path(
+        &self,
+        start: (i32, i32),
+        goal: (i32, i32),
+        connectivity: Connectivity,
+        cost: impl Fn(&T) -> Option<usize>,
+    ) -> Option<Path> {
+        self.shortest_path_guided(start, goal, connectivity, cost, |_| 0)
+    }
+
+    /// Like `shortest_path`, but `estimate` guesses the cost from a cell to `goal` so that
+    /// promising routes are tried first. The route is only sure to be the cheapest if the
+    /// guesses are never too high.
+    pub fn shortest_path_guided(
+        &self,
+        start: (i32, i32),
+        goal: (i32, i32),
+        connectivity: Connectivity,
+        cost: impl Fn(&T) -> Option<usize>,
+        estimate: impl Fn((i32, i32)) -> usize,
+    ) -> Option<Path> {
+        let start = self.normalize(start)?;
+        let goal = self.normalize(goal)?;
+        let cost = &cost;
+        let (cells, cost) = astar(
+            &start,
+            |&coords| {
+                self.adjacent(coords, connectivity)
+                    .filter_map(move |(neighbor, index)| {
+                        cost(&self.map[index]).map(|cost| (neighbor, cost))
+                    })
+            },
+            |&coords| estimate(coords),
+            |&coords| coords == goal,
+        )?;
+        Some(Path { cells, cost })
+    }
+
+    /// A grid of default cells in a new shape, wrapping if this one does.
+    fn reshaped<U>(&self, x_min: i32, x_len: i32, y_min: i32, y_len: i32) -> Grid<U>
+    where
+        U: Default + Clone,
+    {
+        Grid {
+            wrap: self.wrap,
+            ..Grid::new(x_min, x_len, y_min, y_len)
+        }
+    }
 }
 
 impl<T> Index<(i32, i32)> for Grid<T>
@@ -76,8 +664,11 @@ where
     T: Default + Clone,
 {
     type Output = T;
-    fn index(&self, coords: (i32, i32)) -> &Self::Output {
-        &self.map[self.coords_to_index(coords).unwrap()]
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, (x, y): (i32, i32)) -> &Self::Output {
+        &self.map[self.checked_index((x, y))]
     }
 }
 
@@ -85,20 +676,63 @@ impl<T> IndexMut<(i32, i32)> for Grid<T>
 where
     T: Default + Clone,
 {
-    fn index_mut(&mut self, coords: (i32, i32)) -> &mut Self::Output {
-        let index = self.coords_to_index(coords).unwrap();
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, (x, y): (i32, i32)) -> &mut Self::Output {
+        let index = self.checked_index((x, y));
+        &mut self.map[index]
+    }
+}
+
+impl<T> Index<Point> for Grid<T>
+where
+    T: Default + Clone,
+{
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, Point { x, y }: Point) -> &Self::Output {
+        &self.map[self.checked_index((x, y))]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T>
+where
+    T: Default + Clone,
+{
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, Point { x, y }: Point) -> &mut Self::Output {
+        let index = self.checked_index((x, y));
         &mut self.map[index]
     }
 }
 
+/// Indexing by `usize` has to convert, and coordinates too big for an `i32` are off the grid.
+#[track_caller]
+fn narrow<T>(grid: &Grid<T>, (x, y): (usize, usize)) -> usize
+where
+    T: Default + Clone,
+{
+    match (i32::try_from(x), i32::try_from(y)) {
+        (Ok(x), Ok(y)) => grid.checked_index((x, y)),
+        _ => grid.off_grid((
+            x.try_into().unwrap_or(i64::MAX),
+            y.try_into().unwrap_or(i64::MAX),
+        )),
+    }
+}
+
 impl<T> Index<(usize, usize)> for Grid<T>
 where
     T: Default + Clone,
 {
     type Output = T;
+
+    #[track_caller]
     fn index(&self, coords: (usize, usize)) -> &Self::Output {
-        let coords = (coords.0.try_into().unwrap(), coords.1.try_into().unwrap());
-        &self.map[self.coords_to_index(coords.try_into().unwrap()).unwrap()]
+        &self.map[narrow(self, coords)]
     }
 }
 
@@ -106,110 +740,1230 @@ impl<T> IndexMut<(usize, usize)> for Grid<T>
 where
     T: Default + Clone,
 {
+    #[track_caller]
     fn index_mut(&mut self, coords: (usize, usize)) -> &mut Self::Output {
-        let coords = (coords.0.try_into().unwrap(), coords.1.try_into().unwrap());
-        let index = self.coords_to_index(coords).unwrap();
+        let index = narrow(self, coords);
         &mut self.map[index]
     }
 }
 
-pub struct Cells {
-    x: i32,
-    y: i32,
-    x_min: i32,
-    x_max: i32,
-    y_max: i32,
+/// An attempt to reach a cell that isn't on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffGrid {
+    pub coords: (i64, i64),
+    pub bounds: Bounds,
 }
 
-impl std::iter::Iterator for Cells {
-    type Item = (i32, i32);
+impl Display for OffGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({}, {}) is off the grid, which runs from ({}, {}) to ({}, {})",
+            self.coords.0,
+            self.coords.1,
+            self.bounds.x_min,
+            self.bounds.y_min,
+            self.bounds.x_max,
+            self.bounds.y_max
+        )
+    }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.x > self.x_max {
-            self.x = self.x_min;
-            self.y += 1;
+impl std::error::Error for OffGrid {}
+
+/// A grid drawn as text, a row per line; see [`Grid::render`].
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
+    labels: bool,
+    x: RangeInclusive<i32>,
+    y: RangeInclusive<i32>,
+}
+
+impl<T, F> Render<'_, T, F> {
+    /// Numbers the rows down the left, and the columns across the top with their digits
+    /// stacked vertically.
+    pub fn labels(mut self) -> Self {
+        self.labels = true;
+        self
+    }
+
+    /// Only draws the cells within `x` and `y`, or as much of them as is on the grid.
+    pub fn viewport(mut self, x: RangeInclusive<i32>, y: RangeInclusive<i32>) -> Self {
+        self.x = *x.start().max(self.x.start())..=*x.end().min(self.x.end());
+        self.y = *y.start().max(self.y.start())..=*y.end().min(self.y.end());
+        self
+    }
+}
+
+impl<T, F> Display for Render<'_, T, F>
+where
+    T: Default + Clone,
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.x.is_empty() || self.y.is_empty() {
+            return Ok(());
         }
-        if self.y > self.y_max {
-            return None;
+
+        let mut lines = Vec::new();
+
+        let row_label_width = self
+            .y
+            .clone()
+            .map(|y| y.to_string().len())
+            .max()
+            .unwrap_or(0);
+        if self.labels {
+            let columns: Vec<_> = self.x.clone().map(|x| x.to_string()).collect();
+            let height = columns.iter().map(String::len).max().unwrap_or(0);
+            for digit in 0..height {
+                let mut line = " ".repeat(row_label_width + 1);
+                for column in &columns {
+                    // Right-align each label, so the ones digits share the last line.
+                    let padding = height - column.len();
+                    line.push(match digit.checked_sub(padding) {
+                        Some(i) => column.as_bytes()[i].into(),
+                        None => ' ',
+                    });
+                }
+                lines.push(line.trim_end().to_owned());
+            }
         }
-        let result = (self.x, self.y);
-        self.x += 1;
-        Some(result)
+
+        for y in self.y.clone() {
+            let mut line = match self.labels {
+                true => format!("{:>width$} ", y, width = row_label_width),
+                false => String::new(),
+            };
+            line.extend(
+                self.x
+                    .clone()
+                    .map(|x| (self.cell)(self.grid.get((x, y)).unwrap())),
+            );
+            lines.push(line);
+        }
+
+        f.write_str(&lines.join("\n"))
     }
 }
 
-pub trait Neighbors {
-    type Iter: std::iter::Iterator;
-    fn neighbors(&self) -> Self::Iter;
+/// A rectangle of cells, inclusive of its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub x_min: i32,
+    pub x_max: i32,
+    pub y_min: i32,
+    pub y_max: i32,
 }
 
-impl Neighbors for (i32, i32) {
-    type Iter = NeighborsIter;
+impl Bounds {
+    /// Widens the rectangle by `margin` cells on every side.
+    pub fn grow(self, margin: i32) -> Bounds {
+        Bounds {
+            x_min: self.x_min - margin,
+            x_max: self.x_max + margin,
+            y_min: self.y_min - margin,
+            y_max: self.y_max + margin,
+        }
+    }
 
-    fn neighbors(&self) -> Self::Iter {
-        NeighborsIter {
-            center: *self,
-            i: 0,
+    /// The cells in both rectangles.
+    pub fn intersect(self, other: Bounds) -> Bounds {
+        Bounds {
+            x_min: self.x_min.max(other.x_min),
+            x_max: self.x_max.min(other.x_max),
+            y_min: self.y_min.max(other.y_min),
+            y_max: self.y_max.min(other.y_max),
         }
     }
-}
 
-pub struct NeighborsIter {
-    center: (i32, i32),
-    i: usize,
+    pub fn contains(&self, (x, y): (i32, i32)) -> bool {
+        (self.x_min..=self.x_max).contains(&x) && (self.y_min..=self.y_max).contains(&y)
+    }
+
+    /// Every cell in the rectangle, row by row.
+    pub fn cells(&self) -> Cells {
+        Cells {
+            x: self.x_min,
+            // An empty rectangle starts past its last row.
+            y: if self.x_min > self.x_max {
+                self.y_max + 1
+            } else {
+                self.y_min
+            },
+            x_min: self.x_min,
+            x_max: self.x_max,
+            y_max: self.y_max,
+        }
+    }
 }
 
-impl Iterator for NeighborsIter {
-    type Item = (i32, i32);
+/// Which cells count as next to each other when finding regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Cells beside, above and below.
+    Four,
+    /// Diagonals too.
+    Eight,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.i = self.i.saturating_add(1);
-        match self.i {
-            1 => Some((self.center.0 - 1, self.center.1 - 1)),
-            2 => Some((self.center.0, self.center.1 - 1)),
-            3 => Some((self.center.0 + 1, self.center.1 - 1)),
-            4 => Some((self.center.0 - 1, self.center.1)),
-            5 => Some((self.center.0 + 1, self.center.1)),
-            6 => Some((self.center.0 - 1, self.center.1 + 1)),
-            7 => Some((self.center.0, self.center.1 + 1)),
-            8 => Some((self.center.0 + 1, self.center.1 + 1)),
-            _ => None,
+impl Connectivity {
+    fn directions(self) -> &'static [Point] {
+        match self {
+            Connectivity::Four => &Point::DIRECTIONS4,
+            Connectivity::Eight => &Point::DIRECTIONS8,
         }
     }
 }
 
-pub trait Neighbors4 {
-    type Iter: std::iter::Iterator;
-    fn neighbors4(&self) -> Self::Iter;
+/// The connected regions of a grid; see [`Grid::regions`].
+#[derive(Debug, Clone)]
+pub struct Regions {
+    /// Each cell's region, or `None` for cells that weren't passable.
+    pub labels: Grid<Option<usize>>,
+    /// The regions, numbered by the order their first cell comes in `cells()`.
+    pub regions: Vec<Region>,
 }
 
-impl Neighbors4 for (i32, i32) {
-    type Iter = Neighbors4Iter;
+#[derive(Debug, Clone)]
+pub struct Region {
+    pub label: usize,
+    pub size: usize,
+    pub bounds: Bounds,
+    pub cells: Vec<(i32, i32)>,
+}
 
-    fn neighbors4(&self) -> Self::Iter {
-        Neighbors4Iter {
-            center: *self,
-            i: 0,
+/// Either of the ways `Grid::distinct_adjacent` finds a cell's neighbors.
+enum Adjacent<P, S> {
+    Plain(P),
+    Sorted(S),
+}
+
+impl<P, S> Iterator for Adjacent<P, S>
+where
+    P: Iterator<Item = ((i32, i32), usize)>,
+    S: Iterator<Item = ((i32, i32), usize)>,
+{
+    type Item = ((i32, i32), usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Adjacent::Plain(plain) => plain.next(),
+            Adjacent::Sorted(sorted) => sorted.next(),
         }
     }
 }
 
-pub struct Neighbors4Iter {
-    center: (i32, i32),
-    i: usize,
+/// A route between two cells; see [`Grid::shortest_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    /// Every cell on the route, from the start to the goal.
+    pub cells: Vec<(i32, i32)>,
+    pub cost: usize,
 }
 
-impl Iterator for Neighbors4Iter {
-    type Item = (i32, i32);
+impl Path {
+    /// A copy of `grid` with the route's cells set to `mark`, to draw it on a map.
+    pub fn overlay<U>(&self, grid: &Grid<U>, mark: U) -> Grid<U>
+    where
+        U: Default + Clone,
+    {
+        let mut grid = grid.clone();
+        for &coords in &self.cells {
+            grid[coords] = mark.clone();
+        }
+        grid
+    }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.i = self.i.saturating_add(1);
-        match self.i {
-            1 => Some((self.center.0, self.center.1 - 1)),
-            2 => Some((self.center.0 - 1, self.center.1)),
-            3 => Some((self.center.0 + 1, self.center.1)),
-            4 => Some((self.center.0, self.center.1 + 1)),
-            _ => None,
+/// Runs a cellular automaton on a grid, where every cell's next state is worked out from the
+/// current states of the grid, so they all change at once.
+#[derive(Debug, Clone)]
+pub struct Automaton<T> {
+    front: Grid<T>,
+    back: Grid<T>,
+    /// How many steps have been run.
+    pub generation: usize,
+}
+
+impl<T> Automaton<T>
+where
+    T: Default + Clone + PartialEq,
+{
+    pub fn new(grid: Grid<T>) -> Self {
+        Automaton {
+            back: grid.clone(),
+            front: grid,
+            generation: 0,
         }
     }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.front
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.front
+    }
+
+    /// Gives every cell the state `rule` picks for it, returning how many cells changed.
+    pub fn step(&mut self, mut rule: impl FnMut(&Grid<T>, (i32, i32)) -> T) -> usize {
+        let (front, back) = (&self.front, &mut self.back);
+        let width = front.x_len.max(1) as usize;
+        let mut changes = 0;
+        // Rows are stored in order, so walk the two maps alongside the coordinates.
+        let rows = back.map.chunks_mut(width).zip(front.map.chunks(width));
+        for (y, (next_row, row)) in (front.y_min..).zip(rows) {
+            for (x, (next, cell)) in (front.x_min..).zip(next_row.iter_mut().zip(row)) {
+                *next = rule(front, (x, y));
+                if next != cell {
+                    changes += 1;
+                }
+            }
+        }
+        std::mem::swap(&mut self.front, &mut self.back);
+        self.generation += 1;
+        changes
+    }
+
+    /// Steps until a step changes nothing, returning how many steps that took including the
+    /// last one. Gives up with `None` after `limit` steps.
+    pub fn settle(
+        &mut self,
+        limit: Option<usize>,
+        mut rule: impl FnMut(&Grid<T>, (i32, i32)) -> T,
+    ) -> Option<usize> {
+        self.settle_by(limit, |automaton| automaton.step(&mut rule))
+    }
+
+    /// Like `settle`, but each step is whatever `step` does, which returns how many cells it
+    /// changed. That lets a step apply several rules in turn.
+    pub fn settle_by(
+        &mut self,
+        limit: Option<usize>,
+        mut step: impl FnMut(&mut Self) -> usize,
+    ) -> Option<usize> {
+        let mut steps = 0;
+        while limit.is_none_or(|limit| steps < limit) {
+            steps += 1;
+            if step(self) == 0 {
+                return Some(steps);
+            }
+        }
+        None
+    }
+}
+
+/// `offset` wrapped into `0..len`. Stepping off the grid only ever goes a little way past an
+/// edge, so that's handled without dividing.
+fn wrap_offset(offset: i32, len: i32) -> i32 {
+    match offset {
+        _ if (-len..0).contains(&offset) => offset + len,
+        _ if (len..2 * len).contains(&offset) => offset - len,
+        _ => offset.rem_euclid(len),
+    }
+}
+
+/// What a window sees past the edge of a grid; see [`Grid::window`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edge<T> {
+    /// The same value everywhere off the grid.
+    Constant(T),
+    /// The cells on the opposite side, as if the grid wrapped.
+    Wrap,
+}
+
+/// Packs a window of booleans into an integer a bit per cell, reading the rows left to right
+/// from the top, with the first cell as the most significant bit. This makes 3x3 windows into
+/// 9-bit lookup table indices.
+pub fn window_key<const K: usize>(window: &[[bool; K]; K]) -> usize {
+    assert!(
+        K * K <= usize::BITS as usize,
+        "a {0}x{0} window doesn't fit",
+        K
+    );
+    window
+        .iter()
+        .flatten()
+        .fold(0, |key, &bit| key << 1 | usize::from(bit))
+}
+
+/// A rectangle of a grid that can be read through; see [`Grid::view`]. Cells are addressed as
+/// they are on the grid.
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    bounds: Bounds,
+}
+
+/// A rectangle of a grid that can be written through; see [`Grid::view_mut`].
+pub struct ViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    bounds: Bounds,
+}
+
+/// Where in a grid's cells each row of `view` lies, for a grid within `grid`.
+fn row_ranges(grid: Bounds, view: Bounds) -> impl Iterator<Item = std::ops::Range<usize>> {
+    let x_len = grid.x_max - grid.x_min + 1;
+    let x_start = view.x_min - grid.x_min;
+    let width = view.x_max - view.x_min + 1;
+    let rows = view.y_min - grid.y_min..=view.y_max - grid.y_min;
+    // A view with no columns has no rows either.
+    rows.filter(move |_| width > 0).map(move |y| {
+        let start = (y * x_len + x_start) as usize;
+        start..start + width as usize
+    })
+}
+
+impl<T> View<'_, T>
+where
+    T: Default + Clone,
+{
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    pub fn get(&self, coords: impl Into<Point>) -> Option<&T> {
+        let coords = coords.into();
+        match self.bounds.contains(coords.into()) {
+            true => self.grid.get(coords),
+            false => None,
+        }
+    }
+
+    pub fn cells(&self) -> Cells {
+        self.bounds.cells()
+    }
+
+    /// The part of each row within the view, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        row_ranges(self.grid.bounds(), self.bounds).map(|range| &self.grid.map[range])
+    }
+}
+
+impl<T> ViewMut<'_, T>
+where
+    T: Default + Clone,
+{
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    pub fn get(&self, coords: impl Into<Point>) -> Option<&T> {
+        let coords = coords.into();
+        match self.bounds.contains(coords.into()) {
+            true => self.grid.get(coords),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, coords: impl Into<Point>) -> Option<&mut T> {
+        let coords = coords.into();
+        match self.bounds.contains(coords.into()) {
+            true => self.grid.get_mut(coords),
+            false => None,
+        }
+    }
+
+    pub fn cells(&self) -> Cells {
+        self.bounds.cells()
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let ranges = row_ranges(self.grid.bounds(), self.bounds);
+        let mut rest = &mut self.grid.map[..];
+        let mut taken = 0;
+        ranges.map(move |range| {
+            let row = std::mem::take(&mut rest);
+            let (_, row) = row.split_at_mut(range.start - taken);
+            let (row, after) = row.split_at_mut(range.len());
+            rest = after;
+            taken = range.end;
+            row
+        })
+    }
+
+    /// Sets every cell in the view to `value`.
+    pub fn fill(&mut self, value: T) {
+        for row in self.rows_mut() {
+            row.fill(value.clone());
+        }
+    }
+}
+
+impl<T> Index<(i32, i32)> for View<'_, T>
+where
+    T: Default + Clone,
+{
+    type Output = T;
+
+    fn index(&self, coords: (i32, i32)) -> &Self::Output {
+        self.get(coords).unwrap()
+    }
+}
+
+impl<T> Index<(i32, i32)> for ViewMut<'_, T>
+where
+    T: Default + Clone,
+{
+    type Output = T;
+
+    fn index(&self, coords: (i32, i32)) -> &Self::Output {
+        self.get(coords).unwrap()
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for ViewMut<'_, T>
+where
+    T: Default + Clone,
+{
+    fn index_mut(&mut self, coords: (i32, i32)) -> &mut Self::Output {
+        self.get_mut(coords).unwrap()
+    }
+}
+
+/// A grid without edges, where any cell that hasn't been set to something else holds the
+/// background value. Only the other cells are stored.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    pub background: T,
+    cells: HashMap<(i32, i32), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Default + Clone + PartialEq,
+{
+    pub fn new(background: T) -> Self {
+        SparseGrid {
+            background,
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Stores the cells of `grid` that aren't `background`.
+    pub fn from_grid(grid: &Grid<T>, background: T) -> Self {
+        let mut sparse = SparseGrid::new(background);
+        for coords in grid.cells() {
+            sparse.set(coords, grid[coords].clone());
+        }
+        sparse
+    }
+
+    /// A dense copy of the cells within `bounds()`.
+    pub fn to_grid(&self) -> Grid<T> {
+        let Some(bounds) = self.bounds else {
+            return Grid::new(0, 0, 0, 0);
+        };
+        let mut grid = Grid::new(
+            bounds.x_min,
+            bounds.x_max - bounds.x_min + 1,
+            bounds.y_min,
+            bounds.y_max - bounds.y_min + 1,
+        );
+        for coords in bounds.cells() {
+            grid[coords] = self.get(coords).clone();
+        }
+        grid
+    }
+
+    pub fn get(&self, coords: impl Into<Point>) -> &T {
+        self.cells
+            .get(&coords.into().into())
+            .unwrap_or(&self.background)
+    }
+
+    pub fn set(&mut self, coords: impl Into<Point>, value: T) {
+        let coords = coords.into().into();
+        if value == self.background {
+            self.cells.remove(&coords);
+            return;
+        }
+
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => Bounds {
+                x_min: bounds.x_min.min(coords.0),
+                x_max: bounds.x_max.max(coords.0),
+                y_min: bounds.y_min.min(coords.1),
+                y_max: bounds.y_max.max(coords.1),
+            },
+            None => Bounds {
+                x_min: coords.0,
+                x_max: coords.0,
+                y_min: coords.1,
+                y_max: coords.1,
+            },
+        });
+        self.cells.insert(coords, value);
+    }
+
+    /// The smallest rectangle holding every cell that's been set to something besides the
+    /// background. It doesn't shrink when cells are set back to the background.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Every cell within `bounds()`, row by row.
+    pub fn cells(&self) -> Cells {
+        match self.bounds {
+            Some(bounds) => bounds.cells(),
+            None => Bounds {
+                x_min: 0,
+                x_max: -1,
+                y_min: 0,
+                y_max: -1,
+            }
+            .cells(),
+        }
+    }
+
+    /// The `K` by `K` square of cells centred on `coords`, row by row, like `Grid::window`.
+    pub fn window<const K: usize>(&self, (x, y): (i32, i32)) -> [[T; K]; K] {
+        let reach = (K / 2) as i32;
+        std::array::from_fn(|dy| {
+            std::array::from_fn(|dx| {
+                let coords = (x + dx as i32 - reach, y + dy as i32 - reach);
+                self.get(coords).clone()
+            })
+        })
+    }
+
+    /// The cells that aren't the background, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.cells.iter().map(|(&coords, value)| (coords, value))
+    }
+
+    /// The cells around `coords`, including diagonally. There are always eight.
+    pub fn neighbors(&self, coords: (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        coords.neighbors()
+    }
+
+    /// The cells beside, above and below `coords`. There are always four.
+    pub fn neighbors4(&self, coords: (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        coords.neighbors4()
+    }
+}
+
+/// A cell's coordinates, or the offset between two cells. `y` grows downwards, as the rows of
+/// a map do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+
+    /// Steps to the cells beside, above and below, in reading order.
+    pub const DIRECTIONS4: [Point; 4] = [Point::UP, Point::LEFT, Point::RIGHT, Point::DOWN];
+
+    /// Steps to every surrounding cell, in reading order.
+    pub const DIRECTIONS8: [Point; 8] = [
+        Point::new(-1, -1),
+        Point::UP,
+        Point::new(1, -1),
+        Point::LEFT,
+        Point::RIGHT,
+        Point::new(-1, 1),
+        Point::DOWN,
+        Point::new(1, 1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    /// Distance moving only along rows and columns.
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance moving diagonally as well, like a king on a chessboard.
+    pub fn chebyshev(self, other: Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Turns a quarter turn clockwise about the origin, as seen on a map, so `RIGHT` becomes
+    /// `DOWN`.
+    pub fn rotate_right(self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Turns a quarter turn anticlockwise about the origin, so `RIGHT` becomes `UP`.
+    pub fn rotate_left(self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Point {
+        Point { x, y }
+    }
+}
+
+impl From<Point> for (i32, i32) {
+    fn from(point: Point) -> (i32, i32) {
+        (point.x, point.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i32) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+pub struct Cells {
+    x: i32,
+    y: i32,
+    x_min: i32,
+    x_max: i32,
+    y_max: i32,
+}
+
+impl std::iter::Iterator for Cells {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x > self.x_max {
+            self.x = self.x_min;
+            self.y += 1;
+        }
+        if self.y > self.y_max {
+            return None;
+        }
+        let result = (self.x, self.y);
+        self.x += 1;
+        Some(result)
+    }
+}
+
+pub trait Neighbors: Sized {
+    type Iter: std::iter::Iterator<Item = Self>;
+
+    fn neighbors(&self) -> Self::Iter;
+}
+
+impl Neighbors for (i32, i32) {
+    type Iter = NeighborsIter<Self>;
+
+    fn neighbors(&self) -> Self::Iter {
+        NeighborsIter::new(&Point::DIRECTIONS8, *self)
+    }
+}
+
+impl Neighbors for Point {
+    type Iter = NeighborsIter<Self>;
+
+    fn neighbors(&self) -> Self::Iter {
+        NeighborsIter::new(&Point::DIRECTIONS8, *self)
+    }
+}
+
+pub trait Neighbors4: Sized {
+    type Iter: std::iter::Iterator<Item = Self>;
+
+    fn neighbors4(&self) -> Self::Iter;
+}
+
+impl Neighbors4 for (i32, i32) {
+    type Iter = NeighborsIter<Self>;
+
+    fn neighbors4(&self) -> Self::Iter {
+        NeighborsIter::new(&Point::DIRECTIONS4, *self)
+    }
+}
+
+impl Neighbors4 for Point {
+    type Iter = NeighborsIter<Self>;
+
+    fn neighbors4(&self) -> Self::Iter {
+        NeighborsIter::new(&Point::DIRECTIONS4, *self)
+    }
+}
+
+/// Steps from a cell in each of some directions, giving coordinates in the cell's own type.
+pub struct NeighborsIter<P = (i32, i32)> {
+    center: Point,
+    directions: std::slice::Iter<'static, Point>,
+    coords: PhantomData<P>,
+}
+
+/// What `neighbors4` on a pair of coordinates gives; the same type as its `neighbors`.
+pub type Neighbors4Iter = NeighborsIter<(i32, i32)>;
+
+impl<P> NeighborsIter<P>
+where
+    P: Into<Point>,
+{
+    fn new(directions: &'static [Point], center: P) -> Self {
+        NeighborsIter {
+            center: center.into(),
+            directions: directions.iter(),
+            coords: PhantomData,
+        }
+    }
+}
+
+impl<P> Iterator for NeighborsIter<P>
+where
+    P: From<Point>,
+{
+    type Item = P;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &direction = self.directions.next()?;
+        Some((self.center + direction).into())
+    }
+}
+
+/// A grid of booleans packed a bit to a cell, starting at (0, 0). Each row starts on a fresh
+/// word, so whole rows can be combined a word at a time.
+#[derive(Debug)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// Words per row.
+    stride: usize,
+    bits: BitVec<Lsb0, u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(64);
+        BitGrid {
+            width,
+            height,
+            stride,
+            bits: BitVec::repeat(false, height * stride * 64),
+        }
+    }
+
+    /// Packs the cells of `grid`, whatever its origin.
+    pub fn from_grid(grid: &Grid<bool>) -> Self {
+        let mut bits = BitGrid::new(grid.x_len as usize, grid.y_len as usize);
+        for (y, row) in grid.rows().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                bits.set(x, y, cell);
+            }
+        }
+        bits
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        let mut grid = Grid::new(0, self.width as i32, 0, self.height as i32);
+        for (y, row) in grid.rows_mut().enumerate() {
+            for (cell, bit) in row.iter_mut().zip(self.row(y).iter()) {
+                *cell = *bit;
+            }
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the cell is set. Cells off the grid never are.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.row(y)[x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        assert!(x < self.width, "column {} is off the grid", x);
+        assert!(y < self.height, "row {} is off the grid", y);
+        self.row_mut(y).set(x, value);
+    }
+
+    pub fn row(&self, y: usize) -> &BitSlice<Lsb0, u64> {
+        let start = y * self.stride * 64;
+        &self.bits[start..start + self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut BitSlice<Lsb0, u64> {
+        let start = y * self.stride * 64;
+        &mut self.bits[start..start + self.width]
+    }
+
+    /// How many cells are set.
+    pub fn count_ones(&self) -> usize {
+        // The padding at the end of each row is never set.
+        let words = self.bits.as_raw_slice();
+        words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// The smallest rectangle holding every set cell.
+    pub fn bounds(&self) -> Option<Bounds> {
+        let rows: Vec<_> = (0..self.height).filter(|&y| self.row(y).any()).collect();
+        let (&y_min, &y_max) = (rows.first()?, rows.last()?);
+        let x_min = rows.iter().filter_map(|&y| self.row(y).first_one()).min()?;
+        let x_max = rows.iter().filter_map(|&y| self.row(y).last_one()).max()?;
+        Some(Bounds {
+            x_min: x_min as i32,
+            x_max: x_max as i32,
+            y_min: y_min as i32,
+            y_max: y_max as i32,
+        })
+    }
+
+    /// Sets every cell in row `into` that's set in row `from`, a word at a time.
+    pub fn or_rows(&mut self, into: usize, from: usize) {
+        assert!(into < self.height && from < self.height);
+        let words = self.bits.as_mut_raw_slice();
+        for i in 0..self.stride {
+            words[into * self.stride + i] |= words[from * self.stride + i];
+        }
+    }
+
+    /// Moves every cell `by` columns towards column 0. Cells moved off the grid are lost.
+    pub fn shift_left(&mut self, by: usize) {
+        for y in 0..self.height {
+            let row = self.row_mut(y);
+            match by < row.len() {
+                true => row.shift_left(by),
+                false => row.set_all(false),
+            }
+        }
+    }
+
+    /// Moves every cell `by` columns away from column 0.
+    pub fn shift_right(&mut self, by: usize) {
+        for y in 0..self.height {
+            let row = self.row_mut(y);
+            match by < row.len() {
+                true => row.shift_right(by),
+                false => row.set_all(false),
+            }
+        }
+    }
+
+    /// Moves every row `by` rows towards row 0.
+    pub fn shift_up(&mut self, by: usize) {
+        let by = by.min(self.height);
+        let words = self.bits.as_mut_raw_slice();
+        words.copy_within(by * self.stride.., 0);
+        let kept = words.len() - by * self.stride;
+        words[kept..].fill(0);
+    }
+
+    /// Moves every row `by` rows away from row 0.
+    pub fn shift_down(&mut self, by: usize) {
+        let by = by.min(self.height);
+        let words = self.bits.as_mut_raw_slice();
+        let kept = words.len() - by * self.stride;
+        words.copy_within(..kept, by * self.stride);
+        words[..by * self.stride].fill(0);
+    }
+
+    /// Folds the bottom of the grid up over the top along row `at`, like folding paper. Each
+    /// row below `at` is ORed into its mirror image above it, and the grid ends before `at`.
+    pub fn fold_y(&mut self, at: usize) {
+        for from in at + 1..self.height.min(2 * at + 1) {
+            self.or_rows(2 * at - from, from);
+        }
+        self.height = self.height.min(at);
+        self.bits.truncate(self.height * self.stride * 64);
+    }
+
+    /// Folds the right of the grid over the left along column `at`.
+    pub fn fold_x(&mut self, at: usize) {
+        let width = self.width.min(at);
+        for row in self.bits.as_mut_raw_slice().chunks_mut(self.stride) {
+            for w in width / 64..row.len() {
+                // Take the bits from `width` on out of the word, then put back their mirror
+                // images, which all land before `width`.
+                let kept = match w == width / 64 {
+                    true => (1 << (width % 64)) - 1,
+                    false => 0,
+                };
+                let mut folded = row[w] & !kept;
+                row[w] &= kept;
+                while folded != 0 {
+                    let from = w * 64 + folded.trailing_zeros() as usize;
+                    folded &= folded - 1;
+                    if from > at && from <= 2 * at {
+                        let to = 2 * at - from;
+                        row[to / 64] |= 1 << (to % 64);
+                    }
+                }
+            }
+        }
+        self.width = width;
+    }
+}
+
+// bitvec clones and compares a bit at a time, so these go through the words instead.
+impl Clone for BitGrid {
+    fn clone(&self) -> Self {
+        BitGrid {
+            bits: BitVec::from_vec(self.bits.as_raw_slice().to_vec()),
+            ..*self
+        }
+    }
+}
+
+impl PartialEq for BitGrid {
+    fn eq(&self, other: &Self) -> bool {
+        // The padding is never set, so equal rows have equal words, but `fold_x` can leave
+        // either grid with more words to a row than it needs.
+        let words = self.width.div_ceil(64);
+        let (ours, theirs) = (self.bits.as_raw_slice(), other.bits.as_raw_slice());
+        (self.width, self.height) == (other.width, other.height)
+            && (0..self.height)
+                .all(|y| ours[y * self.stride..][..words] == theirs[y * other.stride..][..words])
+    }
+}
+
+impl Eq for BitGrid {}
+
+/// A three-dimensional `Grid`.
+#[derive(Debug, Clone)]
+pub struct Grid3<T> {
+    pub x_min: i32,
+    pub x_len: i32,
+    pub y_min: i32,
+    pub y_len: i32,
+    pub z_min: i32,
+    pub z_len: i32,
+    pub map: Vec<T>,
+}
+
+impl<T> Grid3<T>
+where
+    T: Default + Clone,
+{
+    pub fn new(
+        (x_min, x_len): (i32, i32),
+        (y_min, y_len): (i32, i32),
+        (z_min, z_len): (i32, i32),
+    ) -> Self {
+        let mut map = Vec::new();
+        map.resize((x_len * y_len * z_len) as usize, T::default());
+        Grid3 {
+            x_min,
+            x_len,
+            y_min,
+            y_len,
+            z_min,
+            z_len,
+            map,
+        }
+    }
+
+    fn coords_to_index(&self, (x, y, z): (i32, i32, i32)) -> Option<usize> {
+        let x = x - self.x_min;
+        let y = y - self.y_min;
+        let z = z - self.z_min;
+
+        if !(0..self.x_len).contains(&x)
+            || !(0..self.y_len).contains(&y)
+            || !(0..self.z_len).contains(&z)
+        {
+            return None;
+        }
+
+        Some(((z * self.y_len + y) * self.x_len + x) as usize)
+    }
+
+    pub fn get(&self, coords: (i32, i32, i32)) -> Option<&T> {
+        self.coords_to_index(coords).map(|index| &self.map[index])
+    }
+
+    pub fn get_mut(&mut self, coords: (i32, i32, i32)) -> Option<&mut T> {
+        self.coords_to_index(coords)
+            .map(|index| &mut self.map[index])
+    }
+
+    /// Every cell, a layer of rows at a time.
+    pub fn cells(&self) -> Cells3 {
+        Cells3 {
+            x_len: self.x_len,
+            x_min: self.x_min,
+            y_min: self.y_min,
+            z_min: self.z_min,
+            y_len: self.y_len,
+            index: 0,
+            len: self.map.len(),
+        }
+    }
+}
+
+impl<T> Index<(i32, i32, i32)> for Grid3<T>
+where
+    T: Default + Clone,
+{
+    type Output = T;
+
+    fn index(&self, coords: (i32, i32, i32)) -> &Self::Output {
+        &self.map[self.coords_to_index(coords).unwrap()]
+    }
+}
+
+impl<T> IndexMut<(i32, i32, i32)> for Grid3<T>
+where
+    T: Default + Clone,
+{
+    fn index_mut(&mut self, coords: (i32, i32, i32)) -> &mut Self::Output {
+        let index = self.coords_to_index(coords).unwrap();
+        &mut self.map[index]
+    }
+}
+
+pub struct Cells3 {
+    x_len: i32,
+    x_min: i32,
+    y_min: i32,
+    z_min: i32,
+    y_len: i32,
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for Cells3 {
+    type Item = (i32, i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let index = self.index as i32;
+        self.index += 1;
+        let x = index % self.x_len;
+        let y = index / self.x_len % self.y_len;
+        let z = index / self.x_len / self.y_len;
+        Some((self.x_min + x, self.y_min + y, self.z_min + z))
+    }
+}
+
+/// The cells sharing a face with a cell in three dimensions.
+pub trait Neighbors6 {
+    type Iter: std::iter::Iterator<Item = (i32, i32, i32)>;
+
+    fn neighbors6(&self) -> Self::Iter;
+}
+
+impl Neighbors6 for (i32, i32, i32) {
+    type Iter = Neighbors6Iter;
+
+    fn neighbors6(&self) -> Self::Iter {
+        Neighbors6Iter {
+            center: *self,
+            i: 0,
+        }
+    }
+}
+
+pub struct Neighbors6Iter {
+    center: (i32, i32, i32),
+    i: usize,
+}
+
+impl Iterator for Neighbors6Iter {
+    type Item = (i32, i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, z) = self.center;
+        self.i = self.i.saturating_add(1);
+        match self.i {
+            1 => Some((x, y, z - 1)),
+            2 => Some((x, y - 1, z)),
+            3 => Some((x - 1, y, z)),
+            4 => Some((x + 1, y, z)),
+            5 => Some((x, y + 1, z)),
+            6 => Some((x, y, z + 1)),
+            _ => None,
+        }
+    }
+}
+
+/// The cells touching a cell in three dimensions, even just at a corner.
+pub trait Neighbors26 {
+    type Iter: std::iter::Iterator<Item = (i32, i32, i32)>;
+
+    fn neighbors26(&self) -> Self::Iter;
+}
+
+impl Neighbors26 for (i32, i32, i32) {
+    type Iter = Neighbors26Iter;
+
+    fn neighbors26(&self) -> Self::Iter {
+        Neighbors26Iter {
+            center: *self,
+            i: 0,
+        }
+    }
+}
+
+pub struct Neighbors26Iter {
+    center: (i32, i32, i32),
+    i: i32,
+}
+
+impl Iterator for Neighbors26Iter {
+    type Item = (i32, i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Offsets count through the 3x3x3 cube around the center, skipping the center itself.
+        if self.i == 13 {
+            self.i += 1;
+        }
+        if self.i >= 27 {
+            return None;
+        }
+
+        let (x, y, z) = self.center;
+        let i = self.i;
+        self.i += 1;
+        Some((x + i % 3 - 1, y + i / 3 % 3 - 1, z + i / 9 - 1))
+    }
 }
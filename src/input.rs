@@ -0,0 +1,60 @@
+use color_eyre::eyre::{eyre, Result, WrapErr};
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Where a day's puzzle input should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The real input checked in as `input/<day>.txt`.
+    Default,
+    /// The example from the puzzle text, compiled into the binary.
+    Example,
+    /// Any other file.
+    File(PathBuf),
+    /// Whatever is piped in on stdin.
+    Stdin,
+}
+
+impl Source {
+    pub fn read(&self, day: u8, example: Option<&str>) -> Result<String> {
+        let text = match self {
+            Source::Default => read_file(&default_path(day))?,
+            Source::Example => example
+                .ok_or_else(|| eyre!("day {} has no example input", day))?
+                .to_owned(),
+            Source::File(path) => read_file(path)?,
+            Source::Stdin => {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .wrap_err("reading stdin")?;
+                text
+            }
+        };
+
+        // The parsers all split on "\n" and don't expect a trailing empty line.
+        Ok(text.replace("\r\n", "\n").trim_end_matches('\n').to_owned())
+    }
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Default => f.write_str("default"),
+            Source::Example => f.write_str("example"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => f.write_str("stdin"),
+        }
+    }
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "input", &format!("{}.txt", day)]
+        .iter()
+        .collect()
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).wrap_err_with(|| format!("reading {}", path.display()))
+}
@@ -1 +1,8 @@
+pub mod days;
+pub mod generate;
 pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod solution;
+
+pub use solution::Solution;
@@ -0,0 +1,107 @@
+use reformation::Reformation;
+
+use std::fmt::{self, Display};
+use std::str::Split;
+
+/// Something in a day's input that couldn't be parsed, and where it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, counted in characters.
+    pub column: usize,
+    /// The whole line the problem is on.
+    pub text: String,
+    pub reason: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {} in {:?}",
+            self.day, self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A day's whole input, kept alongside the day so errors can point at where in it they happened.
+#[derive(Debug, Clone, Copy)]
+pub struct Text<'a> {
+    pub day: u8,
+    pub input: &'a str,
+}
+
+impl<'a> Text<'a> {
+    pub fn new(day: u8, input: &'a str) -> Text<'a> {
+        Text { day, input }
+    }
+
+    /// An empty slice at the very end of the input, for reporting things that are missing.
+    pub fn end(&self) -> &'a str {
+        &self.input[self.input.len()..]
+    }
+
+    /// Reports a problem starting at `at`, which has to be a slice of the input. Anything else
+    /// (like an owned copy) gets pointed at the end of the input.
+    pub fn error(&self, at: &str, reason: impl Display) -> ParseError {
+        let start = self.input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= self.input.len() && self.input.is_char_boundary(offset))
+            .unwrap_or(self.input.len());
+
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.input[offset..]
+            .find('\n')
+            .map_or(self.input.len(), |i| offset + i);
+
+        ParseError {
+            day: self.day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: self.input[line_start..line_end].to_owned(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// The input's lines.
+    pub fn lines(&self) -> Split<'a, &'static str> {
+        self.input.split("\n")
+    }
+
+    /// The input's blank-line separated sections.
+    pub fn sections(&self) -> Split<'a, &'static str> {
+        self.input.split("\n\n")
+    }
+
+    /// Splits `s` into exactly two sections around its first blank line, naming what the second
+    /// one should be if it's missing.
+    pub fn two_sections(&self, s: &'a str, second: &str) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once("\n\n").ok_or_else(|| {
+            self.error(
+                &s[s.len()..],
+                format!("expected a blank line before {}", second),
+            )
+        })
+    }
+
+    /// Parses all of `s` as a [`Reformation`] record.
+    pub fn record<T: Reformation<'a>>(&self, s: &'a str) -> Result<T, ParseError> {
+        T::parse(s).map_err(|e| self.error(s, e))
+    }
+
+    /// Parses each line of `s` as a record.
+    pub fn records<T: Reformation<'a>>(&self, s: &'a str) -> Result<Vec<T>, ParseError> {
+        s.split("\n").map(|line| self.record(line)).collect()
+    }
+
+    /// Parses `s` as a comma-separated list, like `3,4,3,1,2`.
+    pub fn comma_separated<T: Reformation<'a>>(&self, s: &'a str) -> Result<Vec<T>, ParseError> {
+        s.split(",").map(|item| self.record(item)).collect()
+    }
+}
@@ -0,0 +1,144 @@
+use color_eyre::eyre::{bail, eyre, Result};
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A day's puzzle: how to read its input and how to solve each part.
+pub trait Solution {
+    const DAY: u8;
+    /// The example from the puzzle text, if there's one the solver can run on.
+    const EXAMPLE: Option<&'static str>;
+
+    /// The puzzle input, parsed into whatever shape suits the solver.
+    type Input;
+    /// Settings the parts read instead of hard-coding them, or `()` if there aren't any.
+    type Options: Options;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input, options: &Self::Options) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input, options: &Self::Options) -> Result<Self::PartTwo>;
+}
+
+/// A day's named settings, like how many days of lanternfish to simulate, so variants of the
+/// puzzle can be tried from the command line.
+pub trait Options: Default {
+    /// Each option's name, and what it changes.
+    const NAMES: &'static [(&'static str, &'static str)];
+
+    /// Sets the option called `name`, which is one of [`Options::NAMES`].
+    fn set(&mut self, name: &str, value: &str) -> Result<()>;
+}
+
+impl Options for () {
+    const NAMES: &'static [(&'static str, &'static str)] = &[];
+
+    fn set(&mut self, name: &str, _: &str) -> Result<()> {
+        bail!("there is no option {:?}", name)
+    }
+}
+
+/// An option that stands in for a number the puzzle fixes, like how many days of lanternfish to
+/// simulate. Days whose parts use different numbers keep one of these for both, so setting it
+/// replaces whichever number the part would have used.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Override<T>(Option<T>);
+
+impl<T: Copy> Override<T> {
+    /// The value that was set, or `default` if there wasn't one.
+    pub fn or(self, default: T) -> T {
+        self.0.unwrap_or(default)
+    }
+}
+
+impl<T> Override<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        self.0 = Some(option_value(name, value)?);
+        Ok(())
+    }
+}
+
+/// Parses an option's value, saying which option it was for if it's bad.
+pub fn option_value<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| eyre!("bad value {:?} for option {}: {}", value, name, e))
+}
+
+/// Options to set, as `(name, value)` pairs like the ones given on the command line.
+pub type Settings = [(String, String)];
+
+/// Builds a day's options from `settings`, starting from the defaults.
+pub fn options<S: Solution>(settings: &Settings) -> Result<S::Options> {
+    let mut options = S::Options::default();
+    for (name, value) in settings {
+        if !S::Options::NAMES.iter().any(|&(known, _)| known == name) {
+            let known: Vec<_> = S::Options::NAMES.iter().map(|&(known, _)| known).collect();
+            match known.is_empty() {
+                true => bail!("day {} has no options", S::DAY),
+                false => bail!(
+                    "day {} has no option {:?}; it has {}",
+                    S::DAY,
+                    name,
+                    known.join(", ")
+                ),
+            }
+        }
+        options.set(name, value)?;
+    }
+    Ok(options)
+}
+
+/// How one part of a day went.
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Result<String>,
+    pub time: Duration,
+}
+
+/// How a whole day went. Parsing has to succeed before any part can run.
+#[derive(Debug)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+/// Parses `input` once, then solves each of `parts` against it with the given options, timing
+/// every step.
+pub fn run<S: Solution>(input: &str, parts: &[u8], settings: &Settings) -> Result<Run> {
+    let options = options::<S>(settings)?;
+
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part_one(&parsed, &options).map(|answer| answer.to_string()),
+                2 => S::part_two(&parsed, &options).map(|answer| answer.to_string()),
+                _ => Err(eyre!("there is no part {}", part)),
+            };
+            PartRun {
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Run { parse_time, parts })
+}
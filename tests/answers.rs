@@ -0,0 +1,254 @@
+//! Pins every day's answers, for both the example from the puzzle text and the real input in
+//! `input/`, so refactors can't quietly change them.
+
+use advent2021::days::*;
+use advent2021::input::Source;
+use advent2021::Solution;
+
+fn real(day: u8) -> String {
+    Source::Default.read(day, None).unwrap()
+}
+
+macro_rules! answers {
+    ($name:ident, $day:ty, $input:expr, $part_one:expr, $part_two:expr) => {
+        #[test]
+        fn $name() {
+            let input = <$day>::parse($input).unwrap();
+            assert_eq!(
+                <$day>::part_one(&input, &Default::default()).unwrap(),
+                $part_one
+            );
+            assert_eq!(
+                <$day>::part_two(&input, &Default::default()).unwrap(),
+                $part_two
+            );
+        }
+    };
+}
+
+answers!(day01_example, day01::Day01, day01::EXAMPLE, 7, 5);
+answers!(day01_real, day01::Day01, &real(1), 1709, 1761);
+
+answers!(day02_example, day02::Day02, day02::EXAMPLE, 150, 900);
+answers!(day02_real, day02::Day02, &real(2), 1636725, 1872757425);
+
+answers!(day03_example, day03::Day03, day03::EXAMPLE, 198, 230);
+answers!(day03_real, day03::Day03, &real(3), 2648450, 2845944);
+
+answers!(day04_example, day04::Day04, day04::EXAMPLE, 4512, 1924);
+answers!(day04_real, day04::Day04, &real(4), 27027, 36975);
+
+answers!(day05_example, day05::Day05, day05::EXAMPLE, 5, 12);
+answers!(day05_real, day05::Day05, &real(5), 4993, 21101);
+
+answers!(
+    day06_example,
+    day06::Day06,
+    day06::EXAMPLE,
+    5934,
+    26984457539
+);
+answers!(day06_real, day06::Day06, &real(6), 362639, 1639854996917);
+
+answers!(day07_example, day07::Day07, day07::EXAMPLE, 37, 168);
+answers!(day07_real, day07::Day07, &real(7), 342730, 92335207);
+
+answers!(day08_example, day08::Day08, day08::EXAMPLE, 26, 61229);
+answers!(day08_real, day08::Day08, &real(8), 362, 1020159);
+
+answers!(day09_example, day09::Day09, day09::EXAMPLE, 15, 1134);
+answers!(day09_real, day09::Day09, &real(9), 489, 1056330);
+
+answers!(day10_example, day10::Day10, day10::EXAMPLE, 26397, 288957);
+answers!(day10_real, day10::Day10, &real(10), 367227, 3583341858);
+
+answers!(day11_example, day11::Day11, day11::EXAMPLE, 1656, 195);
+answers!(day11_real, day11::Day11, &real(11), 1694, 346);
+
+answers!(day12_example, day12::Day12, day12::EXAMPLE, 10, 36);
+answers!(day12_real, day12::Day12, &real(12), 3495, 94849);
+
+answers!(
+    day13_example,
+    day13::Day13,
+    day13::EXAMPLE,
+    17,
+    "#####
+#...#
+#...#
+#...#
+#####"
+);
+answers!(
+    day13_real,
+    day13::Day13,
+    &real(13),
+    607,
+    ".##..###..####.#....###..####.####.#...
+#..#.#..#....#.#....#..#.#.......#.#...
+#....#..#...#..#....#..#.###....#..#...
+#....###...#...#....###..#.....#...#...
+#..#.#....#....#....#....#....#....#...
+.##..#....####.####.#....#....####.####"
+);
+
+answers!(
+    day14_example,
+    day14::Day14,
+    day14::EXAMPLE,
+    1588,
+    2188189693529
+);
+answers!(day14_real, day14::Day14, &real(14), 3230, 3542388214529);
+
+answers!(day15_example, day15::Day15, day15::EXAMPLE, 40, 315);
+answers!(day15_real, day15::Day15, &real(15), 388, 2819);
+
+answers!(day16_example, day16::Day16, day16::EXAMPLE, 31, 54);
+answers!(day16_real, day16::Day16, &real(16), 974, 180616437720);
+
+answers!(day17_example, day17::Day17, day17::EXAMPLE, 45, 112);
+answers!(day17_real, day17::Day17, &real(17), 7750, 4120);
+
+answers!(day18_example, day18::Day18, day18::EXAMPLE, 4140, 3993);
+answers!(day18_real, day18::Day18, &real(18), 3574, 4763);
+
+answers!(day19_example, day19::Day19, day19::EXAMPLE, 79, 3621);
+answers!(day19_real, day19::Day19, &real(19), 442, 11079);
+
+answers!(day20_example, day20::Day20, day20::EXAMPLE, 35, 3351);
+answers!(day20_real, day20::Day20, &real(20), 5786, 16757);
+
+answers!(
+    day21_example,
+    day21::Day21,
+    day21::EXAMPLE,
+    739785,
+    444356092776315
+);
+answers!(day21_real, day21::Day21, &real(21), 518418, 116741133558209);
+
+answers!(
+    day22_example,
+    day22::Day22,
+    day22::EXAMPLE,
+    590784,
+    39769202357779
+);
+answers!(
+    day22_real,
+    day22::Day22,
+    &real(22),
+    537042,
+    1304385553084863
+);
+
+#[test]
+fn day03_single_and_repeated_numbers() {
+    let input = day03::Day03::parse("101").unwrap();
+    assert_eq!(
+        day03::Day03::part_two(&input, &Default::default()).unwrap(),
+        25
+    );
+
+    let input = day03::Day03::parse("101\n101").unwrap();
+    assert!(day03::Day03::part_two(&input, &Default::default()).is_err());
+
+    // A column with no most common bit counts as a zero in the gamma rate, so it's 0b100 and
+    // the epsilon rate is 0b011.
+    let input = day03::Day03::parse("100\n110").unwrap();
+    assert_eq!(
+        day03::Day03::part_one(&input, &Default::default()).unwrap(),
+        12
+    );
+}
+
+#[test]
+fn day09_needs_three_basins() {
+    let input = day09::Day09::parse("129\n999\n929").unwrap();
+    assert!(day09::Day09::part_two(&input, &Default::default()).is_err());
+}
+
+#[test]
+fn day11_and_day25_give_up_on_endless_simulations() {
+    // Two octopuses that never flash on the same step.
+    let input = day11::Day11::parse("19").unwrap();
+    let error = day11::Day11::part_two(&input, &()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "the octopuses don't all flash at once in 10000 steps"
+    );
+
+    // A sea cucumber that always has somewhere to go.
+    let input = day25::Day25::parse(">.").unwrap();
+    let error = day25::Day25::part_one(&input, &()).unwrap_err();
+    assert_eq!(error.to_string(), "the herds still move after 10000 steps");
+}
+
+#[test]
+fn day22_mini_example() {
+    let input = day22::Day22::parse(day22::MINI_EXAMPLE).unwrap();
+    assert_eq!(
+        day22::Day22::part_one(&input, &Default::default()).unwrap(),
+        39
+    );
+}
+
+#[test]
+fn day22_mega_example() {
+    let input = day22::Day22::parse(day22::MEGA_EXAMPLE).unwrap();
+    assert_eq!(
+        day22::Day22::part_two(&input, &Default::default()).unwrap(),
+        2758514936282235
+    );
+}
+
+// Part two searches a much bigger burrow and takes close to a minute even when optimised.
+#[test]
+fn day23_example_part_one() {
+    let input = day23::Day23::parse(day23::EXAMPLE).unwrap();
+    assert_eq!(
+        day23::Day23::part_one(&input, &Default::default()).unwrap(),
+        12521
+    );
+}
+
+#[test]
+#[ignore]
+fn day23_example_part_two() {
+    let input = day23::Day23::parse(day23::EXAMPLE).unwrap();
+    assert_eq!(
+        day23::Day23::part_two(&input, &Default::default()).unwrap(),
+        44169
+    );
+}
+
+#[test]
+fn day23_real_part_one() {
+    let input = day23::Day23::parse(&real(23)).unwrap();
+    assert_eq!(
+        day23::Day23::part_one(&input, &Default::default()).unwrap(),
+        15109
+    );
+}
+
+#[test]
+#[ignore]
+fn day23_real_part_two() {
+    let input = day23::Day23::parse(&real(23)).unwrap();
+    assert_eq!(
+        day23::Day23::part_two(&input, &Default::default()).unwrap(),
+        53751
+    );
+}
+
+answers!(
+    day24_real,
+    day24::Day24,
+    &real(24),
+    99196997985942,
+    84191521311611
+);
+
+answers!(day25_example, day25::Day25, day25::EXAMPLE, 58, "(none)");
+answers!(day25_real, day25::Day25, &real(25), 432, "(none)");
@@ -0,0 +1,144 @@
+//! Generated inputs have to be ones the solvers accept, and the same seed has to make the same
+//! input every time.
+
+use advent2021::days;
+use advent2021::generate::{self, Rng};
+
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Small enough to solve quickly in a debug build.
+fn size(generator: &generate::Generator) -> usize {
+    generator.size.map_or(0, |size| size.default.min(20))
+}
+
+#[test]
+fn every_day_solves_its_generated_input() {
+    for (day, generator) in days::ALL.iter().zip(&generate::ALL) {
+        assert_eq!(day.number, generator.day);
+        // Day 23's part two takes minutes in a debug build.
+        let parts: &[u8] = if day.number == 23 { &[1] } else { &[1, 2] };
+
+        let input = generator.generate(size(generator), 0);
+        let run = day
+            .run(&input, parts, &[])
+            .unwrap_or_else(|e| panic!("day {}: {:?}", day.number, e));
+        for part in run.parts {
+            if let Err(e) = part.answer {
+                panic!("day {} part {}: {:?}", day.number, part.part, e);
+            }
+        }
+    }
+}
+
+/// Generates day `number`'s input and runs both parts on it, in a thread so that a generator or
+/// solver that never finishes can be reported rather than hanging the test.
+fn solve_generated(number: u8, size: usize, seed: u64) -> Result<(), String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let index = usize::from(number) - 1;
+        let input = generate::ALL[index].generate(size, seed);
+        let outcome = days::ALL[index]
+            .run(&input, &[1, 2], &[])
+            .map_err(|e| e.to_string())
+            .and_then(|run| {
+                run.parts.into_iter().try_for_each(|part| {
+                    part.answer
+                        .map(drop)
+                        .map_err(|e| format!("part {}: {}", part.part, e))
+                })
+            });
+        let _ = sender.send(outcome);
+    });
+    match receiver.recv_timeout(Duration::from_secs(30)) {
+        Ok(outcome) => outcome,
+        Err(RecvTimeoutError::Timeout) => Err("didn't finish".to_owned()),
+        Err(RecvTimeoutError::Disconnected) => Err("panicked".to_owned()),
+    }
+}
+
+#[test]
+fn tiny_inputs_solve() {
+    let mut failures = Vec::new();
+    for generator in generate::ALL.iter().filter(|g| g.size.is_some()) {
+        for size in 0..=5 {
+            for seed in 0..3 {
+                if let Err(e) = solve_generated(generator.day, size, seed) {
+                    failures.push(format!(
+                        "day {}, size {}, seed {}: {}",
+                        generator.day, size, seed, e
+                    ));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn seeds_reproduce_inputs() {
+    for generator in &generate::ALL {
+        let size = size(generator);
+        assert_eq!(generator.generate(size, 7), generator.generate(size, 7));
+        assert_ne!(generator.generate(size, 7), generator.generate(size, 8));
+    }
+}
+
+#[test]
+fn sizes_count_what_they_say() {
+    let lines = |day: usize, size| generate::ALL[day - 1].generate(size, 0).lines().count();
+    assert_eq!(lines(1, 500), 500);
+    assert_eq!(lines(15, 40), 40);
+    assert_eq!(lines(22, 1000), 1000);
+
+    let scanners = generate::ALL[18].generate(50, 0).matches("scanner").count();
+    assert_eq!(scanners, 50);
+}
+
+/// Big inputs have answers too big for the narrowest types that hold the real ones.
+#[test]
+fn large_inputs_solve() {
+    for (day, size) in [(3, 70_000), (7, 5000)] {
+        if let Err(e) = solve_generated(day, size, 0) {
+            panic!("day {}, size {}: {}", day, size, e);
+        }
+    }
+}
+
+#[test]
+fn day22_later_steps_miss_the_initialization_region() {
+    let input = generate::ALL[21].generate(500, 0);
+    for line in input.lines().skip(20) {
+        let axes: Vec<(i64, i64)> = line
+            .split(['=', ','])
+            .skip(1)
+            .step_by(2)
+            .map(|axis| {
+                let (min, max) = axis.split_once("..").unwrap();
+                (min.parse().unwrap(), max.parse().unwrap())
+            })
+            .collect();
+        assert_eq!(axes.len(), 3, "{}", line);
+        assert!(
+            axes.iter().any(|&(min, max)| max < -50 || min > 50),
+            "{} reaches into the region",
+            line
+        );
+    }
+}
+
+#[test]
+fn rng_stays_in_range() {
+    let mut rng = Rng::new(1);
+    for _ in 0..1000 {
+        assert!((-3..=3).contains(&rng.range(-3, 3)));
+        assert!(rng.below(10) < 10);
+    }
+
+    let mut items: Vec<_> = (0..100).collect();
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..100).collect::<Vec<_>>());
+    items.sort_unstable();
+    assert_eq!(items, (0..100).collect::<Vec<_>>());
+}
@@ -0,0 +1,721 @@
+//! The shared grid types and the algorithms over them.
+
+use advent2021::grid::{
+    window_key, Automaton, BitGrid, Bounds, Connectivity, Edge, Grid, Grid3, Neighbors,
+    Neighbors26, Neighbors4, Neighbors4Iter, Neighbors6, NeighborsIter, Point, SparseGrid,
+};
+use advent2021::parse::Text;
+
+fn wall(c: char) -> Result<bool, &'static str> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("expected `#` or `.`"),
+    }
+}
+
+#[test]
+fn parse_infers_the_size() {
+    let text = Text::new(0, "#..\n.#.\n..#\n...");
+    let grid = Grid::parse(&text, text.input, wall).unwrap();
+    assert_eq!(
+        (grid.x_min, grid.x_len, grid.y_min, grid.y_len),
+        (0, 3, 0, 4)
+    );
+    assert!(grid[(0, 0)] && grid[(1, 1)] && grid[(2, 2)]);
+    assert_eq!(grid.map.iter().filter(|&&wall| wall).count(), 3);
+}
+
+#[test]
+fn parse_reports_ragged_rows() {
+    let text = Text::new(0, "#..\n.#\n..#");
+    let error = Grid::parse(&text, text.input, wall).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.reason, "expected 3 cells like the first row");
+
+    let text = Text::new(0, "#..\n.#..\n..#");
+    let error = Grid::parse(&text, text.input, wall).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+}
+
+#[test]
+fn parse_reports_cells_the_decoder_rejects() {
+    let text = Text::new(0, "#..\n.#.\n.x#");
+    let error = Grid::parse(&text, text.input, wall).unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (3, 2, ".x#")
+    );
+    assert_eq!(error.reason, "expected `#` or `.`");
+
+    let text = Text::new(0, "");
+    assert!(Grid::parse(&text, text.input, wall).is_err());
+}
+
+#[test]
+fn parse_reads_part_of_the_input() {
+    let text = Text::new(0, "header\n\n#.\n.#");
+    let (_, map) = text.two_sections(text.input, "the map").unwrap();
+    let grid = Grid::parse(&text, map, wall).unwrap();
+    assert_eq!((grid.x_len, grid.y_len), (2, 2));
+
+    let text = Text::new(0, "header\n\n#.\n.?");
+    let (_, map) = text.two_sections(text.input, "the map").unwrap();
+    let error = Grid::parse(&text, map, wall).unwrap_err();
+    assert_eq!((error.line, error.column), (4, 2));
+}
+
+#[test]
+fn render_draws_a_row_per_line() {
+    let text = Text::new(0, "#..\n.#.\n..#");
+    let grid = Grid::parse(&text, text.input, wall).unwrap();
+    let render = |&wall: &bool| if wall { '#' } else { '.' };
+    assert_eq!(grid.render(render).to_string(), text.input);
+    assert_eq!(
+        grid.render(render).viewport(1..=5, -1..=1).to_string(),
+        "..\n#."
+    );
+    assert_eq!(grid.render(render).viewport(3..=5, 0..=2).to_string(), "");
+}
+
+#[test]
+fn render_labels_rows_and_columns() {
+    let mut grid = Grid::<u8>::new(-2, 13, 8, 3);
+    grid[(-2i32, 8i32)] = 1;
+    grid[(10i32, 10i32)] = 2;
+    let digit = |&n: &u8| char::from(b'0' + n);
+    assert_eq!(
+        grid.render(digit).labels().to_string(),
+        [
+            "   --          1",
+            "   2101234567890",
+            " 8 1000000000000",
+            " 9 0000000000000",
+            "10 0000000000002",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn wrapping_grids_wrap_around_any_origin() {
+    let mut grid = Grid::<u8>::new(-3, 4, 5, 2).wrapping();
+    grid[(0i32, 6i32)] = 1;
+    assert_eq!(grid.normalize((4, 8)), Some((0, 6)));
+    assert_eq!(grid.normalize((-7, 4)), Some((-3, 6)));
+    assert_eq!(grid.normalize((13, 20)), Some((-3, 6)));
+    assert_eq!(grid.get((4, 8)), Some(&1));
+    assert_eq!(grid[(-4i32, 6i32)], 1);
+
+    let mut neighbors: Vec<_> = grid.neighbors4((0, 6)).collect();
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, [(-3, 6), (-1, 6), (0, 5), (0, 5)]);
+}
+
+#[test]
+fn bounded_grids_leave_out_cells_off_the_edge() {
+    let grid = Grid::<u8>::new(-3, 4, 5, 2);
+    assert_eq!(grid.normalize((1, 5)), None);
+    assert_eq!(grid.get((-3, 7)), None);
+    assert_eq!(grid.neighbors((-3, 5)).count(), 3);
+    assert_eq!(grid.neighbors4((-1, 6)).count(), 3);
+}
+
+#[test]
+fn sparse_grids_track_their_bounds() {
+    let mut grid = SparseGrid::new('.');
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.cells().count(), 0);
+
+    grid.set((2, -1), '#');
+    grid.set((-1, 3), '#');
+    grid.set((100, 100), '.');
+    assert_eq!(
+        grid.bounds(),
+        Some(Bounds {
+            x_min: -1,
+            x_max: 2,
+            y_min: -1,
+            y_max: 3
+        })
+    );
+    assert_eq!(grid.cells().count(), 20);
+    assert_eq!(*grid.get((-1, 3)), '#');
+    assert_eq!(*grid.get((-50, 50)), '.');
+    assert_eq!(grid.neighbors((-50, 50)).count(), 8);
+
+    grid.set((2, -1), '.');
+    assert_eq!(grid.iter().count(), 1);
+}
+
+#[test]
+fn sparse_grids_convert_to_and_from_dense_ones() {
+    let text = Text::new(0, "#..\n.#.\n...");
+    let dense = Grid::parse(&text, text.input, wall).unwrap();
+    let sparse = SparseGrid::from_grid(&dense, false);
+    assert_eq!(sparse.iter().count(), 2);
+
+    let cropped = sparse.to_grid();
+    assert_eq!(
+        (cropped.x_min, cropped.x_len, cropped.y_min, cropped.y_len),
+        (0, 2, 0, 2)
+    );
+    assert_eq!(
+        cropped
+            .render(|&wall| if wall { '#' } else { '.' })
+            .to_string(),
+        "#.\n.#"
+    );
+}
+
+#[test]
+fn grid3_addresses_cells_from_its_origin() {
+    let mut grid = Grid3::<u8>::new((-1, 2), (5, 3), (10, 4));
+    assert_eq!(grid.map.len(), 24);
+    grid[(0, 7, 13)] = 1;
+    assert_eq!(grid.get((0, 7, 13)), Some(&1));
+    assert_eq!(grid.get((1, 7, 13)), None);
+    assert_eq!(grid.get((-1, 5, 9)), None);
+
+    let cells: Vec<_> = grid.cells().collect();
+    assert_eq!(cells.len(), 24);
+    assert_eq!(cells[..3], [(-1, 5, 10), (0, 5, 10), (-1, 6, 10)]);
+    assert_eq!(cells[23], (0, 7, 13));
+    assert!(cells.iter().all(|&cell| grid.get(cell).is_some()));
+}
+
+#[test]
+fn neighbors_in_three_dimensions() {
+    let center = (4, -2, 0);
+    let faces: Vec<_> = center.neighbors6().collect();
+    assert_eq!(faces.len(), 6);
+    assert!(faces
+        .iter()
+        .all(|&(x, y, z)| { (x - 4).abs() + (y + 2).abs() + z.abs() == 1 }));
+
+    let mut all: Vec<_> = center.neighbors26().collect();
+    all.sort_unstable();
+    all.dedup();
+    assert_eq!(all.len(), 26);
+    assert!(!all.contains(&center));
+    assert!(all
+        .iter()
+        .all(|&(x, y, z)| { (x - 4).abs().max((y + 2).abs()).max(z.abs()) == 1 }));
+}
+
+#[test]
+fn point_arithmetic() {
+    let a = Point::new(3, -4);
+    let b: Point = (1, 2).into();
+    assert_eq!(a + b, Point::new(4, -2));
+    assert_eq!(a - b, Point::new(2, -6));
+    assert_eq!(a * 3, Point::new(9, -12));
+    assert_eq!(-a, Point::new(-3, 4));
+    assert_eq!(a.manhattan(Point::ORIGIN), 7);
+    assert_eq!(a.chebyshev(b), 6);
+    assert_eq!(<(i32, i32)>::from(a), (3, -4));
+
+    assert_eq!(Point::RIGHT.rotate_right(), Point::DOWN);
+    assert_eq!(Point::RIGHT.rotate_left(), Point::UP);
+    assert_eq!(a.rotate_right().rotate_right(), -a);
+    assert_eq!(a.rotate_left().rotate_right(), a);
+}
+
+#[test]
+fn points_and_tuples_have_the_same_neighbors() {
+    let point: Vec<_> = Point::new(5, 5)
+        .neighbors()
+        .map(<(i32, i32)>::from)
+        .collect();
+    let tuple: Vec<_> = (5, 5).neighbors().collect();
+    assert_eq!(point, tuple);
+    assert_eq!(tuple[..3], [(4, 4), (5, 4), (6, 4)]);
+
+    let point: Vec<_> = Point::new(5, 5).neighbors4().collect();
+    assert_eq!(point, Point::DIRECTIONS4.map(|d| Point::new(5, 5) + d));
+
+    // The iterator types keep the names they had before `Point`.
+    let tuple: Neighbors4Iter = (5, 5).neighbors4();
+    let _: NeighborsIter = (5, 5).neighbors();
+    assert_eq!(tuple.count(), 4);
+
+    let mut grid = Grid::<u8>::new(0, 3, 0, 3);
+    grid[Point::new(1, 2)] = 7;
+    assert_eq!(grid.get((1, 2)), Some(&7));
+}
+
+/// A grid of digits with its origin away from (0, 0).
+fn digits(rows: &str) -> Grid<u8> {
+    let text = Text::new(0, rows);
+    let mut grid = Grid::parse(&text, text.input, |c| {
+        c.to_digit(10).map(|d| d as u8).ok_or("expected a digit")
+    })
+    .unwrap();
+    grid.x_min = -1;
+    grid.y_min = 10;
+    grid
+}
+
+fn show(grid: &Grid<u8>) -> String {
+    grid.render(|&d| char::from(b'0' + d)).to_string()
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = digits("123\n456");
+    let rows: Vec<_> = grid.rows().collect();
+    assert_eq!(rows, [[1, 2, 3], [4, 5, 6]]);
+
+    let columns: Vec<Vec<_>> = grid.columns().map(|c| c.copied().collect()).collect();
+    assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+    assert_eq!(grid.column(1).count(), 2);
+    assert_eq!(grid.column(2).count(), 0);
+}
+
+#[test]
+fn views_read_and_write_part_of_the_grid() {
+    let mut grid = digits("123\n456\n789");
+    let bounds = Bounds {
+        x_min: 0,
+        x_max: 5,
+        y_min: 11,
+        y_max: 12,
+    };
+
+    let view = grid.view(bounds);
+    assert_eq!(view.bounds().x_max, 1);
+    assert_eq!(view.rows().collect::<Vec<_>>(), [[5, 6], [8, 9]]);
+    assert_eq!(view[(0, 11)], 5);
+    assert_eq!(view.get((-1, 11)), None);
+    assert_eq!(view.cells().count(), 4);
+
+    let mut view = grid.view_mut(bounds);
+    view[(1, 12)] = 0;
+    view.rows_mut().next().unwrap()[0] = 0;
+    assert_eq!(show(&grid), "123\n406\n780");
+
+    grid.view_mut(bounds).fill(7);
+    assert_eq!(show(&grid), "123\n477\n777");
+}
+
+#[test]
+fn transforms_keep_the_origin() {
+    let grid = digits("123\n456");
+
+    let transposed = grid.transpose();
+    assert_eq!(
+        (transposed.x_min, transposed.y_min, transposed.x_len),
+        (10, -1, 2)
+    );
+    assert_eq!(show(&transposed), "14\n25\n36");
+    assert_eq!(transposed[(11i32, 0i32)], 5);
+
+    assert_eq!(show(&grid.flip_x()), "321\n654");
+    assert_eq!(show(&grid.flip_y()), "456\n123");
+
+    let rotated = grid.rotate90();
+    assert_eq!((rotated.x_min, rotated.y_min), (-1, 10));
+    assert_eq!(show(&rotated), "41\n52\n63");
+    let full_turn = rotated.rotate90().rotate90().rotate90();
+    assert_eq!(full_turn.map, grid.map);
+}
+
+#[test]
+fn grow_and_crop() {
+    let grid = digits("12\n34");
+    let grown = grid.grow(1, 0);
+    assert_eq!((grown.x_min, grown.y_min), (-2, 9));
+    assert_eq!(show(&grown), "0000\n0120\n0340\n0000");
+    assert_eq!(grown[(-1i32, 10i32)], 1);
+
+    let cropped = grown.crop(grid.bounds());
+    assert_eq!((cropped.x_min, cropped.y_min), (grid.x_min, grid.y_min));
+    assert_eq!(cropped.map, grid.map);
+
+    let corner = grown.crop(Bounds {
+        x_min: 0,
+        x_max: 9,
+        y_min: 0,
+        y_max: 10,
+    });
+    assert_eq!(show(&corner), "00\n20");
+}
+
+#[test]
+fn tile_transforms_each_copy() {
+    let grid = digits("12\n34");
+    let tiled = grid.tile(3, 2, |x_tile, y_tile, &d| {
+        (d + (x_tile + 4 * y_tile) as u8) % 10
+    });
+    assert_eq!((tiled.x_min, tiled.y_min), (-1, 10));
+    assert_eq!(show(&tiled), "122334\n344556\n566778\n788990");
+}
+
+#[test]
+fn flood_fill_follows_the_connectivity() {
+    let text = Text::new(0, "#.#\n.##\n#..");
+    let grid = Grid::parse(&text, text.input, wall).unwrap();
+    let open = |&wall: &bool| !wall;
+
+    let mut four = grid.flood_fill((1, 0), Connectivity::Four, open);
+    four.sort_unstable();
+    assert_eq!(four, [(1, 0)]);
+
+    let mut eight = grid.flood_fill((1, 0), Connectivity::Eight, open);
+    eight.sort_unstable();
+    assert_eq!(eight, [(0, 1), (1, 0), (1, 2), (2, 2)]);
+
+    assert!(grid.flood_fill((0, 0), Connectivity::Four, open).is_empty());
+    assert!(grid.flood_fill((5, 5), Connectivity::Four, open).is_empty());
+
+    // Wrapping joins the top row to the bottom one.
+    let wrapping = grid.clone().wrapping();
+    let mut wrapped = wrapping.flood_fill((1, 0), Connectivity::Four, open);
+    wrapped.sort_unstable();
+    assert_eq!(wrapped, [(1, 0), (1, 2), (2, 2)]);
+}
+
+#[test]
+fn regions_are_labelled_in_reading_order() {
+    let text = Text::new(0, "..#.\n#.#.\n##..\n.#..");
+    let grid = Grid::parse(&text, text.input, wall).unwrap();
+    let found = grid.regions(Connectivity::Four, |&wall| !wall);
+
+    let sizes: Vec<_> = found.regions.iter().map(|r| r.size).collect();
+    assert_eq!(sizes, [3, 6, 1]);
+    assert_eq!(found.labels[(0i32, 0i32)], Some(0));
+    assert_eq!(found.labels[(2i32, 0i32)], None);
+    assert_eq!(found.labels[(3i32, 3i32)], Some(1));
+    assert_eq!(found.labels[(0i32, 3i32)], Some(2));
+
+    let second = &found.regions[1];
+    assert_eq!(second.label, 1);
+    assert_eq!(
+        second.bounds,
+        Bounds {
+            x_min: 2,
+            x_max: 3,
+            y_min: 0,
+            y_max: 3
+        }
+    );
+    assert!(second
+        .cells
+        .iter()
+        .all(|&cell| found.labels[cell] == Some(1)));
+
+    assert_eq!(
+        grid.regions(Connectivity::Eight, |&wall| !wall)
+            .regions
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn distances_count_steps_around_walls() {
+    let text = Text::new(0, "..#.\n#.#.\n....");
+    let grid = Grid::parse(&text, text.input, wall).unwrap();
+    let distances = grid.distances((0, 0), Connectivity::Four, |&wall| !wall);
+    let steps = distances.render(|steps| match steps {
+        Some(n) => char::from(b'0' + *n as u8),
+        None => '#',
+    });
+    assert_eq!(steps.to_string(), "01#7\n#2#6\n4345");
+
+    let diagonal = grid.distances((0, 0), Connectivity::Eight, |&wall| !wall);
+    assert_eq!(diagonal[(3i32, 0i32)], Some(4));
+    assert!(grid
+        .distances((2, 0), Connectivity::Four, |&wall| !wall)
+        .map
+        .iter()
+        .all(Option::is_none));
+}
+
+#[test]
+fn shortest_paths_weigh_each_cell() {
+    let grid = digits("131\n191\n111");
+    let (start, goal) = ((-1, 10), (1, 12));
+    let risk = |&d: &u8| Some(usize::from(d));
+
+    let path = grid
+        .shortest_path(start, goal, Connectivity::Four, risk)
+        .unwrap();
+    assert_eq!(path.cost, 4);
+    assert_eq!(path.cells, [(-1, 10), (-1, 11), (-1, 12), (0, 12), (1, 12)]);
+    assert_eq!(show(&path.overlay(&grid, 0)), "031\n091\n000");
+
+    let distance = |(x, y): (i32, i32)| (goal.0 - x + goal.1 - y) as usize;
+    let guided = grid
+        .shortest_path_guided(start, goal, Connectivity::Four, risk, distance)
+        .unwrap();
+    assert_eq!(guided.cost, path.cost);
+
+    // Walls of 9s can't be crossed.
+    let walled = |&d: &u8| (d != 9).then_some(usize::from(d));
+    let grid = digits("191\n191\n191");
+    assert_eq!(
+        grid.shortest_path(start, goal, Connectivity::Four, walled),
+        None
+    );
+}
+
+/// Conway's game of life.
+fn life(grid: &Grid<bool>, cell: (i32, i32)) -> bool {
+    let alive = grid.neighbors(cell).filter(|&n| grid[n]).count();
+    alive == 3 || (grid[cell] && alive == 2)
+}
+
+#[test]
+fn automata_update_every_cell_at_once() {
+    let text = Text::new(0, ".....\n..#..\n..#..\n..#..\n.....");
+    let grid = Grid::parse(&text, text.input, wall).unwrap();
+    let draw = |grid: &Grid<bool>| {
+        grid.render(|&alive| if alive { '#' } else { '.' })
+            .to_string()
+    };
+
+    let mut blinker = Automaton::new(grid);
+    assert_eq!(blinker.step(life), 4);
+    assert_eq!(draw(blinker.grid()), ".....\n.....\n.###.\n.....\n.....");
+    assert_eq!(blinker.step(life), 4);
+    assert_eq!(blinker.generation, 2);
+    assert_eq!(blinker.settle(Some(10), life), None);
+    assert_eq!(blinker.generation, 12);
+    assert_eq!(draw(&blinker.into_grid()), text.input);
+}
+
+#[test]
+fn automata_settle_at_a_fixed_point() {
+    let text = Text::new(0, "#...\n.#..\n..#.\n....");
+    let grid = Grid::parse(&text, text.input, wall).unwrap();
+    let mut life = Automaton::new(grid);
+    // The diagonal shrinks to one cell, then that dies out.
+    assert_eq!(life.settle(None, self::life), Some(3));
+    assert!(life.grid().map.iter().all(|&alive| !alive));
+}
+
+#[test]
+fn automata_settle_by_steps_of_several_rules() {
+    let text = Text::new(0, ".....\n..#..\n..#..\n..#..\n.....");
+    let grid = Grid::parse(&text, text.input, wall).unwrap();
+    let mut blinker = Automaton::new(grid);
+    // Two generations of a blinker take it back where it started, but each changes cells.
+    assert_eq!(
+        blinker.settle_by(Some(5), |blinker| blinker.step(life) + blinker.step(life)),
+        None
+    );
+    assert_eq!(blinker.generation, 10);
+
+    let text = Text::new(0, "....\n.##.\n.##.\n....");
+    let mut still = Automaton::new(Grid::parse(&text, text.input, wall).unwrap());
+    assert_eq!(
+        still.settle_by(None, |still| still.step(life) + still.step(life)),
+        Some(1)
+    );
+}
+
+fn bits(rows: &str) -> BitGrid {
+    let text = Text::new(0, rows);
+    BitGrid::from_grid(&Grid::parse(&text, text.input, wall).unwrap())
+}
+
+fn show_bits(bits: &BitGrid) -> String {
+    bits.to_grid()
+        .render(|&set| if set { '#' } else { '.' })
+        .to_string()
+}
+
+#[test]
+fn bit_grids_pack_cells() {
+    let mut grid = BitGrid::new(130, 3);
+    grid.set(129, 2, true);
+    grid.set(64, 0, true);
+    assert!(grid.get(129, 2) && grid.get(64, 0));
+    assert!(!grid.get(130, 2) && !grid.get(0, 3));
+    assert_eq!(grid.count_ones(), 2);
+    assert_eq!(grid.row(2).count_ones(), 1);
+    assert_eq!(
+        grid.bounds(),
+        Some(Bounds {
+            x_min: 64,
+            x_max: 129,
+            y_min: 0,
+            y_max: 2
+        })
+    );
+
+    grid.or_rows(1, 2);
+    grid.or_rows(1, 0);
+    assert_eq!(grid.row(1).count_ones(), 2);
+    assert_eq!(grid.clone(), grid);
+    assert_eq!(BitGrid::new(4, 4).bounds(), None);
+}
+
+#[test]
+fn bit_grids_shift() {
+    let mut grid = bits("#..#\n.##.\n....");
+    grid.shift_right(1);
+    assert_eq!(show_bits(&grid), ".#..\n..##\n....");
+    grid.shift_left(2);
+    assert_eq!(show_bits(&grid), "....\n##..\n....");
+    grid.shift_down(1);
+    assert_eq!(show_bits(&grid), "....\n....\n##..");
+    grid.shift_up(2);
+    assert_eq!(show_bits(&grid), "##..\n....\n....");
+    grid.shift_left(10);
+    assert_eq!(grid.count_ones(), 0);
+}
+
+#[test]
+fn bit_grids_fold() {
+    let mut grid = bits("#....\n..#..\n.....\n....#\n#...#");
+    grid.fold_y(2);
+    assert_eq!((grid.width(), grid.height()), (5, 2));
+    assert_eq!(show_bits(&grid), "#...#\n..#.#");
+    grid.fold_x(2);
+    // The dot on the fold line goes.
+    assert_eq!(show_bits(&grid), "#.\n#.");
+    assert_eq!(grid.count_ones(), 2);
+
+    // Columns past the first word fold back into it.
+    let mut wide = BitGrid::new(201, 1);
+    wide.set(170, 0, true);
+    wide.set(100, 0, true);
+    wide.fold_x(100);
+    assert_eq!((wide.width(), wide.count_ones()), (100, 1));
+    assert!(wide.get(30, 0));
+
+    // A folded grid still has its old row length, but equals one made at its new size.
+    let mut narrow = BitGrid::new(100, 1);
+    narrow.set(30, 0, true);
+    assert_eq!(wide, narrow);
+    narrow.set(31, 0, true);
+    assert_ne!(wide, narrow);
+}
+
+#[test]
+#[should_panic(expected = "row 3 is off the grid")]
+fn setting_a_bit_off_the_grid_panics() {
+    BitGrid::new(4, 3).set(0, 3, true);
+}
+
+#[test]
+fn try_get_reports_where_the_grid_is() {
+    let mut grid = digits("12\n34");
+    assert_eq!(grid.try_get((0, 11)), Ok(&4));
+    *grid.try_get_mut(Point::new(0, 11)).unwrap() = 5;
+    assert_eq!(grid[(0i32, 11i32)], 5);
+
+    let error = grid.try_get((1, 11)).unwrap_err();
+    assert_eq!(error.coords, (1, 11));
+    assert_eq!(error.bounds, grid.bounds());
+    assert_eq!(
+        error.to_string(),
+        "(1, 11) is off the grid, which runs from (-1, 10) to (0, 11)"
+    );
+    assert!(grid.try_get_mut((-2, 10)).is_err());
+
+    // Wrapping grids have no cells off them.
+    assert_eq!(grid.clone().wrapping().try_get((1, 11)), Ok(&3));
+    assert_eq!(unsafe { *grid.get_unchecked((-1, 10)) }, 1);
+}
+
+#[test]
+#[should_panic(expected = "(7, 0) is off the grid, which runs from (0, 0) to (1, 1)")]
+fn indexing_off_the_grid_says_where() {
+    let grid = Grid::<u8>::new(0, 2, 0, 2);
+    let _ = grid[(7i32, 0i32)];
+}
+
+#[test]
+#[should_panic(expected = "(9223372036854775807, 0) is off the grid")]
+fn indexing_with_huge_coordinates_says_so() {
+    let grid = Grid::<u8>::new(0, 2, 0, 2);
+    let _ = grid[(usize::MAX, 0usize)];
+}
+
+#[test]
+fn neighbors_of_skip_cells_off_the_grid() {
+    let grid = digits("123\n456\n789");
+    let corner: Vec<_> = grid.neighbors4_of((-1, 10)).collect();
+    assert_eq!(corner, [((0, 10), &2), ((-1, 11), &4)]);
+
+    let center: u8 = grid.neighbors8_of((0, 11)).map(|(_, &d)| d).sum();
+    assert_eq!(center, 45 - 5);
+    assert_eq!(grid.neighbors8_of((1, 12)).count(), 3);
+}
+
+#[test]
+fn neighbors_of_mut_change_each_cell_once() {
+    let mut grid = digits("123\n456\n789");
+    for (_, d) in grid.neighbors8_of_mut((1, 12)) {
+        *d = 0;
+    }
+    assert_eq!(show(&grid), "123\n400\n709");
+
+    // Left and right are the same cell when the grid is two wide and wraps.
+    let mut grid = digits("12\n34").wrapping();
+    let cells: Vec<_> = grid.neighbors4_of_mut((-1, 10)).map(|(c, _)| c).collect();
+    assert_eq!(cells, [(0, 10), (-1, 11)]);
+    let cells: Vec<_> = grid.neighbors4_of((-1, 10)).map(|(c, _)| c).collect();
+    assert_eq!(cells, [(0, 10), (-1, 11)]);
+    let cells: Vec<_> = grid.neighbors8_of((-1, 10)).map(|(c, _)| c).collect();
+    let cells_mut: Vec<_> = grid.neighbors8_of_mut((-1, 10)).map(|(c, _)| c).collect();
+    assert_eq!(cells, [(0, 10), (-1, 11), (0, 11)]);
+    assert_eq!(cells, cells_mut);
+    for (_, d) in grid.neighbors4_of_mut((-1, 10)) {
+        *d += 1;
+    }
+    assert_eq!(show(&grid), "13\n44");
+}
+
+#[test]
+fn windows_see_past_the_edge() {
+    let grid = digits("123\n456\n789");
+    assert_eq!(
+        grid.window::<3>((0, 11), &Edge::Constant(0)),
+        [[1, 2, 3], [4, 5, 6], [7, 8, 9]]
+    );
+    assert_eq!(
+        grid.window::<3>((-1, 10), &Edge::Constant(0)),
+        [[0, 0, 0], [0, 1, 2], [0, 4, 5]]
+    );
+    assert_eq!(
+        grid.window::<3>((-1, 10), &Edge::Wrap),
+        [[9, 7, 8], [3, 1, 2], [6, 4, 5]]
+    );
+    assert_eq!(
+        grid.window::<2>((1, 12), &Edge::Constant(0)),
+        [[5, 6], [8, 9]]
+    );
+
+    let windows: Vec<_> = grid.windows::<1>(Edge::Wrap).collect();
+    assert_eq!(windows.len(), 9);
+    assert_eq!(windows[4], ((0, 11), [[5]]));
+
+    // The edge wins over a grid's own wrapping.
+    let grid = grid.wrapping();
+    assert_eq!(
+        grid.window::<3>((-1, 10), &Edge::Constant(0)),
+        [[0, 0, 0], [0, 1, 2], [0, 4, 5]]
+    );
+    assert_eq!(
+        grid.window::<3>((-1, 10), &Edge::Wrap),
+        [[9, 7, 8], [3, 1, 2], [6, 4, 5]]
+    );
+}
+
+#[test]
+fn window_keys_read_the_first_cell_as_the_top_bit() {
+    // The example from the puzzle text for day 20.
+    let window = [[false; 3], [true, false, false], [false, true, false]];
+    assert_eq!(window_key(&window), 34);
+    assert_eq!(window_key(&[[true; 3]; 3]), 511);
+    assert_eq!(window_key(&[[true]]), 1);
+
+    let mut image = SparseGrid::new(false);
+    image.set((5, 5), true);
+    assert_eq!(window_key(&image.window::<3>((6, 6))), 256);
+    assert_eq!(window_key(&image.window::<3>((4, 4))), 1);
+}
@@ -0,0 +1,155 @@
+//! Days' named options change what the parts compute, and bad ones are reported.
+
+use advent2021::days::{self, *};
+
+/// Runs `parts` of `day` on its example with the given options.
+fn answers(day: u8, parts: &[u8], options: &[(&str, &str)]) -> Result<Vec<String>, String> {
+    let day = &days::ALL[usize::from(day) - 1];
+    let options: Vec<_> = options
+        .iter()
+        .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+        .collect();
+    let run = day
+        .run(day.example.unwrap(), parts, &options)
+        .map_err(|e| e.to_string())?;
+    Ok(run
+        .parts
+        .into_iter()
+        .map(|part| part.answer.unwrap())
+        .collect())
+}
+
+#[test]
+fn options_override_both_parts() {
+    // The puzzle text gives the answers for the example after 18 days and 10 steps.
+    assert_eq!(
+        answers(6, &[1, 2], &[("days", "18")]).unwrap(),
+        ["26", "26"]
+    );
+    assert_eq!(
+        answers(14, &[1, 2], &[("steps", "10")]).unwrap(),
+        ["1588", "1588"]
+    );
+    assert_eq!(
+        answers(20, &[1, 2], &[("passes", "2")]).unwrap(),
+        ["35", "35"]
+    );
+    assert_eq!(
+        answers(20, &[1, 2], &[("passes", "50")]).unwrap(),
+        ["3351", "3351"]
+    );
+}
+
+#[test]
+fn day07_positions() {
+    // Lining up at 3 takes 39 fuel in part one, from the puzzle text.
+    let answers = answers(7, &[1], &[("positions", "3..4")]).unwrap();
+    assert_eq!(answers[0], "39");
+
+    assert!(self::answers(7, &[1], &[("positions", "4..4")]).is_err());
+    assert!(self::answers(7, &[1], &[("positions", "4")]).is_err());
+
+    // Far-off positions cost more fuel than part two can count, which is an error rather than
+    // a wrapped-around answer.
+    let far = [(
+        "positions".to_owned(),
+        "-2000000000..-1999999999".to_owned(),
+    )];
+    let run = days::ALL[6].run(day07::EXAMPLE, &[1, 2], &far).unwrap();
+    assert_eq!(run.parts[0].answer.as_ref().unwrap(), "20000000049");
+    let error = run.parts[1].answer.as_ref().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "too much fuel to count to line the crabs up at -2000000000"
+    );
+}
+
+#[test]
+fn day21_win_at() {
+    let answers = answers(21, &[1], &[("win_at", "1000")]).unwrap();
+    assert_eq!(answers[0], "739785");
+
+    // Part two always plays to 21.
+    let answers = self::answers(21, &[2], &[("win_at", "1000")]).unwrap();
+    assert_eq!(answers[0], "444356092776315");
+}
+
+#[test]
+fn day22_region() {
+    let input = day22::MINI_EXAMPLE;
+    let day = &days::ALL[21];
+    let region = |size: &str| {
+        let options = [("region".to_owned(), size.to_owned())];
+        let run = day.run(input, &[1], &options).unwrap();
+        run.parts[0].answer.as_ref().unwrap().clone()
+    };
+    assert_eq!(region("50"), "39");
+    // Every cube the mini example turns on lies beyond 9 on some axis.
+    assert_eq!(region("9"), "0");
+    // Cubes crossing the edge of the region only count inside it.
+    assert_eq!(region("10"), "1");
+    assert_eq!(region("12"), "20");
+
+    let options = [("region".to_owned(), "-1".to_owned())];
+    assert!(day.run(input, &[1], &options).is_err());
+}
+
+#[test]
+fn bad_options_are_reported() {
+    let error = answers(6, &[1, 2], &[("weeks", "3")]).unwrap_err();
+    assert_eq!(error, "day 6 has no option \"weeks\"; it has days");
+
+    let error = answers(1, &[1], &[("days", "3")]).unwrap_err();
+    assert_eq!(error, "day 1 has no options");
+
+    let error = answers(6, &[1, 2], &[("days", "lots")]).unwrap_err();
+    assert!(error.starts_with("bad value \"lots\" for option days"));
+}
+
+#[test]
+fn the_registry_lists_each_days_options() {
+    let names: Vec<_> = days::ALL
+        .iter()
+        .flat_map(|day| day.options.iter().map(move |&(name, _)| (day.number, name)))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            (6, "days"),
+            (7, "positions"),
+            (14, "steps"),
+            (20, "passes"),
+            (21, "win_at"),
+            (22, "region"),
+        ]
+    );
+}
+
+#[test]
+fn day20_odd_passes_that_light_the_background_are_an_error() {
+    // Dark pixels with no lit neighbors light up, so after an odd pass everything is lit.
+    let input = format!("#{}\n\n#.\n..", ".".repeat(511));
+    let day = &days::ALL[19];
+    let passes = |n: &str| {
+        let options = [("passes".to_owned(), n.to_owned())];
+        let run = day.run(&input, &[1], &options).unwrap();
+        run.parts[0]
+            .answer
+            .as_ref()
+            .map_err(|e| e.to_string())
+            .cloned()
+    };
+    assert!(passes("1")
+        .unwrap_err()
+        .starts_with("infinitely many pixels are lit"));
+    assert!(passes("2").is_ok());
+}
+
+#[test]
+fn day06_too_many_days_is_an_error() {
+    let day = &days::ALL[5];
+    let options = [("days".to_owned(), "1000".to_owned())];
+    let run = day.run(day.example.unwrap(), &[1], &options).unwrap();
+    let error = run.parts[0].answer.as_ref().unwrap_err().to_string();
+    assert!(error.starts_with("more than"), "{}", error);
+}
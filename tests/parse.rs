@@ -0,0 +1,41 @@
+//! The shared input helpers handle the common shapes and report problems instead of panicking.
+
+use advent2021::parse::Text;
+
+#[test]
+fn comma_separated() {
+    let text = Text::new(6, "3,4,3,1,2");
+    assert_eq!(
+        text.comma_separated::<u8>(text.input),
+        Ok(vec![3, 4, 3, 1, 2])
+    );
+
+    let text = Text::new(6, "3,4,-,1,2");
+    let error = text.comma_separated::<u8>(text.input).unwrap_err();
+    assert_eq!((error.line, error.column), (1, 5));
+}
+
+#[test]
+fn records() {
+    let text = Text::new(1, "199\n200\n208");
+    assert_eq!(text.records::<u32>(text.input), Ok(vec![199, 200, 208]));
+
+    let text = Text::new(1, "199\n\n208");
+    let error = text.records::<u32>(text.input).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+}
+
+#[test]
+fn sections() {
+    let text = Text::new(13, "6,10\n0,14\n\nfold along y=7");
+    assert_eq!(
+        text.two_sections(text.input, "the folds"),
+        Ok(("6,10\n0,14", "fold along y=7"))
+    );
+    assert_eq!(text.sections().count(), 2);
+
+    let text = Text::new(13, "6,10\n0,14");
+    let error = text.two_sections(text.input, "the folds").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 5));
+    assert_eq!(error.reason, "expected a blank line before the folds");
+}
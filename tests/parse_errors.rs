@@ -0,0 +1,110 @@
+//! Bad input should be reported with where it went wrong, not panic.
+
+use advent2021::days::*;
+use advent2021::parse::ParseError;
+use advent2021::Solution;
+
+/// Parses `input` as day `S`, expecting it to fail, and returns where.
+fn parse_error<S: Solution>(input: &str) -> ParseError
+where
+    S::Input: std::fmt::Debug,
+{
+    let report = S::parse(input).expect_err("parsing should fail");
+    report
+        .downcast_ref::<ParseError>()
+        .unwrap_or_else(|| panic!("not a ParseError: {:?}", report))
+        .clone()
+}
+
+fn position(error: &ParseError) -> (u8, usize, usize, &str) {
+    (error.day, error.line, error.column, error.text.as_str())
+}
+
+#[test]
+fn reports_the_offending_line() {
+    let error = parse_error::<day01::Day01>("199\n200\n2o8\n210");
+    assert_eq!(position(&error), (1, 3, 1, "2o8"));
+}
+
+#[test]
+fn reports_the_offending_column() {
+    let error = parse_error::<day07::Day07>("16,1,2,x,4");
+    assert_eq!(position(&error), (7, 1, 8, "16,1,2,x,4"));
+
+    let error = parse_error::<day06::Day06>("3,4,9,1");
+    assert_eq!(position(&error), (6, 1, 5, "3,4,9,1"));
+
+    let error = parse_error::<day03::Day03>("00100\n11120\n10110");
+    assert_eq!(position(&error), (3, 2, 4, "11120"));
+
+    let error = parse_error::<day16::Day16>("A0016G880162017C3686B18A3D4780");
+    assert_eq!(
+        position(&error),
+        (16, 1, 6, "A0016G880162017C3686B18A3D4780")
+    );
+}
+
+#[test]
+fn reports_errors_inside_nested_structures() {
+    let error = parse_error::<day18::Day18>("[1,2]\n[[1,2],[3;4]]");
+    assert_eq!(position(&error), (18, 2, 9, "[[1,2],[3;4]]"));
+    assert_eq!(error.reason, "expected a number or a pair");
+}
+
+#[test]
+fn reports_truncated_input() {
+    let error = parse_error::<day16::Day16>("A0016C88");
+    assert_eq!(
+        error.reason,
+        "the transmission ends in the middle of a packet"
+    );
+
+    let error = parse_error::<day04::Day04>("7,4,9\n\n22 13 17 11  0\n 8  2 23  4 24");
+    assert_eq!(position(&error), (4, 4, 15, " 8  2 23  4 24"));
+}
+
+#[test]
+fn reports_reformation_failures() {
+    let error =
+        parse_error::<day22::Day22>("on x=-20..26,y=-36..17,z=-47..7\nof x=1..2,y=1..2,z=1..2");
+    assert_eq!(position(&error), (22, 2, 1, "of x=1..2,y=1..2,z=1..2"));
+    assert_eq!(error.reason, "expected `on` or `off`");
+
+    let error = parse_error::<day19::Day19>("--- scanner 0 ---\n404,-588,-901\n528,-643,409,1");
+    assert_eq!(position(&error), (19, 3, 1, "528,-643,409,1"));
+}
+
+#[test]
+fn formats_with_the_position() {
+    let error = parse_error::<day25::Day25>("v...>>.vv>\n.vv>>.vx..");
+    assert_eq!(
+        error.to_string(),
+        "day 25, line 2, column 8: expected `.`, `>` or `v` in \".vv>>.vx..\""
+    );
+}
+
+#[test]
+fn rejects_empty_input() {
+    for input in ["", "\n\n"] {
+        let error = parse_error::<day03::Day03>(input);
+        assert_eq!(position(&error), (3, 1, 1, ""));
+        assert_eq!(error.reason, "expected binary numbers");
+    }
+}
+
+#[test]
+fn reports_pairs_without_insertion_rules() {
+    let error = parse_error::<day14::Day14>("\n\nNN -> C");
+    assert_eq!(error.reason, "expected a polymer template");
+
+    let error = parse_error::<day14::Day14>("NNé\n\nNN -> N");
+    assert_eq!(position(&error), (14, 1, 3, "NNé"));
+
+    let error = parse_error::<day14::Day14>("NCN\n\nNC -> N\nNN -> N");
+    assert_eq!(position(&error), (14, 1, 2, "NCN"));
+    assert_eq!(error.reason, "no insertion rule for this pair");
+
+    let error = parse_error::<day14::Day14>("NN\n\nNN -> C\nNC -> N");
+    assert_eq!(position(&error), (14, 3, 1, "NN -> C"));
+    assert_eq!(error.reason, "inserts a pair that has no rule of its own");
+}